@@ -1,17 +1,95 @@
 extern crate libusb;
 extern crate clap;
 
-// use std::slice;
-use std::time::Duration;
-use clap::{App, Arg, SubCommand};
-use std::fs::File;
-use std::path::Path;
-use std::error::Error;
-use std::io::Write;
+use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
 
 use whiskers::RFCatBLDevice;
+use whiskers::FirmwareImage;
+use whiskers::FlashProgress;
+use whiskers::Opened;
 use whiskers::all_rfcatbls;
+use whiskers::rfcatbl_filter;
 
+/* the same --usb-all / --usb-addr / --usb-vp selection whiskers has */
+fn usb_select<'a, 'b>(subcommand: App<'a, 'b>) -> App<'a, 'b> {
+    subcommand
+        .arg(Arg::with_name("usb-vp")
+            .help("select specific USB vendor & product combos (format: <vendor hex>,<product hex>)")
+            .long("usb-vp")
+            .takes_value(true)
+            .multiple(true)
+            .required(false))
+        .group(ArgGroup::with_name("usb-select")
+            .required(true)
+            .arg("all-usb")
+            .arg("usb-addr"))
+        .arg(Arg::with_name("usb-addr")
+            .help("select specific USB addresses (format: <bus nbr>,<dev nbr>)")
+            .long("usb-addr")
+            .takes_value(true)
+            .multiple(true)
+            .required(false))
+        .arg(Arg::with_name("all-usb")
+            .help("select all USB addresses")
+            .long("all-usb")
+            .long("usb-all")
+            .required(false))
+}
+
+/* a line for each device that wouldn't open; the rest carry on */
+fn report_failures<'a>(opened: Opened<RFCatBLDevice<'a>>) -> Vec<RFCatBLDevice<'a>> {
+    for failure in opened.failed.iter() {
+        println!("Error: RFCat bootloader: {}", failure);
+    }
+    opened.devices
+}
+
+/* the bootloader devices a subcommand's usb-select picked, or None (already complained) */
+fn selected<'a>(context: &'a libusb::Context, argm: &ArgMatches) -> Option<Vec<RFCatBLDevice<'a>>> {
+    let usb_vp = match argm.is_present("usb-vp") {
+        false => None,
+        true => Some(argm.values_of("usb-vp").unwrap().collect()),
+    };
+    let usb_addr = match argm.is_present("usb-addr") {
+        false => None,
+        true => Some(argm.values_of("usb-addr").unwrap().collect()),
+    };
+    match rfcatbl_filter(context, usb_addr, usb_vp) {
+        Ok(opened) => Some(report_failures(opened)),
+        Err(err) => {
+            println!("Error: {}", err);
+            None
+        },
+    }
+}
+
+fn read_image(hexfile: &str) -> Option<FirmwareImage> {
+    match std::fs::read_to_string(hexfile) {
+        Ok(text) => match FirmwareImage::parse(&text) {
+            Ok(image) => Some(image),
+            Err(err) => {
+                println!("Error: {}: {}", hexfile, err);
+                None
+            },
+        },
+        Err(err) => {
+            println!("Error: {}: {}", hexfile, err);
+            None
+        },
+    }
+}
+
+fn print_header(rfcatbl: &RFCatBLDevice) {
+    println!("RFCatBL: b{:03} d{:03} v{:04x} p{:04x}",
+             rfcatbl.bus_number,
+             rfcatbl.address,
+             rfcatbl.vendor_id,
+             rfcatbl.product_id);
+}
+
+fn print_progress(p: FlashProgress) {
+    println!("  page {:2} ({}/{})", p.page, p.done, p.total);
+}
 
 fn main() {
     let matches = App::new("whiskers-bl")
@@ -21,27 +99,56 @@ fn main() {
         .subcommand(
             SubCommand::with_name("list")
                 .about("list attached RFCats in bootloader mode"))
-        .subcommand(
+        .subcommand(usb_select(
             SubCommand::with_name("run")
-                .about("exit bootloader mode")
-                .arg(Arg::with_name("device")
-                    //  .value_name("DEVICE")
-                     .help("Bootloader ACM device")
+                .about("exit bootloader mode")))
+        .subcommand(usb_select(
+            SubCommand::with_name("reset")
+                .about("restart the bootloader")))
+        .subcommand(usb_select(
+            SubCommand::with_name("erase")
+                .about("erase all of user flash")))
+        .subcommand(usb_select(
+            SubCommand::with_name("crc")
+                .about("have the bootloader check the user code's CRC")))
+        .subcommand(usb_select(
+            SubCommand::with_name("flash")
+                .about("write an Intel HEX image to RFCats in bootloader mode")
+                .arg(Arg::with_name("hexfile")
+                     .help("Intel HEX firmware image")
+                     .required(true)
+                     .index(1))))
+        .subcommand(usb_select(
+            SubCommand::with_name("dump")
+                .about("read the application flash of an RFCat in bootloader mode out to a file")
+                .arg(Arg::with_name("outfile")
+                     .help("where to write the image")
                      .required(true)
-                    //  .takes_value(true)
-                     .index(1)
-        ))
+                     .index(1))
+                .arg(Arg::with_name("binary")
+                     .help("write a raw binary (starting at the user code base) instead of Intel HEX")
+                     .long("binary")
+                     .required(false))))
+        .subcommand(usb_select(
+            SubCommand::with_name("verify")
+                .about("compare RFCats in bootloader mode against an Intel HEX image")
+                .arg(Arg::with_name("hexfile")
+                     .help("Intel HEX firmware image")
+                     .required(true)
+                     .index(1))))
         .get_matches();
     match matches.subcommand_name() {
         Some("list") => {
             let context = libusb::Context::new().unwrap();
-            let rfcatbls = all_rfcatbls(&context);
+            let rfcatbls = match all_rfcatbls(&context) {
+                Ok(opened) => report_failures(opened),
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
             for rfcatbl in rfcatbls.iter() {
-                println!("RFCatBL: b{:03} d{:03} v{:04x} p{:04x}",
-                         rfcatbl.bus_number,
-                         rfcatbl.address,
-                         rfcatbl.vendor_id,
-                         rfcatbl.product_id);
+                print_header(rfcatbl);
                 match rfcatbl.manufacturer() {
                     Ok(mstr) => {
                         println!("  {}", mstr);
@@ -60,41 +167,134 @@ fn main() {
                 }
             }
         },
-        // Some("blrun-lusb") => {
-        //     let context = libusb::Context::new().unwrap();
-        //     let rfcatbls = all_rfcatbls(&context);
-        //     for rfcatbl in rfcatbls.iter() {
-        //         println!("RFCatBL: b{:03} d{:03} v{:04x} p{:04x}",
-        //                  rfcatbl.bus_number,
-        //                  rfcatbl.address,
-        //                  rfcatbl.vendor_id,
-        //                  rfcatbl.product_id);
-        //         match rfcatbl.run() {
-        //             Ok(oktho) => {
-        //                 println!("  {}", oktho);
-        //             },
-        //             Err(err) => {
-        //                 println!("  Error: {}", err);
-        //             },
-        //         }
-        //     }
-        // },
-        Some("run") => {
-            let subm = matches.subcommand_matches("run").unwrap();
-            let devfile = subm.value_of("device").unwrap();
-            let mut file = match File::create(Path::new(devfile)) {
-                Err(why) => panic!("couldn't open {}: {}",
-                                   devfile,
-                                   why.description()),
-                Ok(file) => file,
+        Some(cmd @ "run") | Some(cmd @ "reset") | Some(cmd @ "erase") => {
+            let argm = matches.subcommand_matches(cmd).unwrap();
+            let context = libusb::Context::new().unwrap();
+            let mut rfcatbls = match selected(&context, argm) {
+                Some(rfcatbls) => rfcatbls,
+                None => return,
             };
-            match file.write(":00000001FF\n".as_bytes()) {
-                Err(why) => panic!("couldn't write {}: {}",
-                                   devfile,
-                                   why.description()),
-                Ok(_) => (),
+            for rfcatbl in rfcatbls.iter_mut() {
+                print_header(rfcatbl);
+                let result = match cmd {
+                    "run" => rfcatbl.run(),
+                    "reset" => rfcatbl.reset(),
+                    _ => rfcatbl.erase_all(),
+                };
+                match result {
+                    Ok(()) => {
+                        println!("  ok");
+                    },
+                    Err(err) => {
+                        println!("  Error: {}", err);
+                    },
+                }
             }
-        }
+        },
+        Some("crc") => {
+            let argm = matches.subcommand_matches("crc").unwrap();
+            let context = libusb::Context::new().unwrap();
+            let mut rfcatbls = match selected(&context, argm) {
+                Some(rfcatbls) => rfcatbls,
+                None => return,
+            };
+            for rfcatbl in rfcatbls.iter_mut() {
+                print_header(rfcatbl);
+                match rfcatbl.check_crc() {
+                    Ok(intact) => {
+                        println!("  {}", intact);
+                    },
+                    Err(err) => {
+                        println!("  Error: {}", err);
+                    },
+                }
+            }
+        },
+        Some("flash") => {
+            let argm = matches.subcommand_matches("flash").unwrap();
+            let image = match read_image(argm.value_of("hexfile").unwrap()) {
+                Some(image) => image,
+                None => return,
+            };
+            let context = libusb::Context::new().unwrap();
+            let mut rfcatbls = match selected(&context, argm) {
+                Some(rfcatbls) => rfcatbls,
+                None => return,
+            };
+            for rfcatbl in rfcatbls.iter_mut() {
+                print_header(rfcatbl);
+                match rfcatbl.flash(&image, print_progress) {
+                    Ok(()) => {
+                        println!("  flashed");
+                    },
+                    Err(err) => {
+                        println!("  Error: {}", err);
+                    },
+                }
+            }
+        },
+        Some("dump") => {
+            let argm = matches.subcommand_matches("dump").unwrap();
+            let outfile = argm.value_of("outfile").unwrap();
+            let context = libusb::Context::new().unwrap();
+            let mut rfcatbls = match selected(&context, argm) {
+                Some(rfcatbls) => rfcatbls,
+                None => return,
+            };
+            if rfcatbls.len() != 1 {
+                println!("Error: need exactly one RFCat in bootloader mode, selected {}", rfcatbls.len());
+                return;
+            }
+            let rfcatbl = &mut rfcatbls[0];
+            print_header(rfcatbl);
+            let image = match rfcatbl.dump(print_progress) {
+                Ok(image) => image,
+                Err(err) => {
+                    println!("  Error: {}", err);
+                    return;
+                },
+            };
+            let written = match argm.is_present("binary") {
+                true => std::fs::write(outfile, image.to_binary().1),
+                false => std::fs::write(outfile, image.to_hex()),
+            };
+            match written {
+                Ok(()) => {
+                    println!("  dumped to {}", outfile);
+                },
+                Err(err) => {
+                    println!("  Error: {}: {}", outfile, err);
+                },
+            }
+        },
+        Some("verify") => {
+            let argm = matches.subcommand_matches("verify").unwrap();
+            let image = match read_image(argm.value_of("hexfile").unwrap()) {
+                Some(image) => image,
+                None => return,
+            };
+            let context = libusb::Context::new().unwrap();
+            let mut rfcatbls = match selected(&context, argm) {
+                Some(rfcatbls) => rfcatbls,
+                None => return,
+            };
+            for rfcatbl in rfcatbls.iter_mut() {
+                print_header(rfcatbl);
+                match rfcatbl.verify(&image, |_| ()) {
+                    Ok(ref mismatched) if mismatched.is_empty() => {
+                        println!("  matches");
+                    },
+                    Ok(mismatched) => {
+                        for page in mismatched.iter() {
+                            println!("  page {:2} differs", page);
+                        }
+                    },
+                    Err(err) => {
+                        println!("  Error: {}", err);
+                    },
+                }
+            }
+        },
         None | _ => (),
     }
 }
@@ -3,13 +3,167 @@ extern crate clap;
 
 use clap::{App, SubCommand, Arg, ArgGroup};
 use whiskers::rfcat_filter;
-use std::time::Instant;
+use whiskers::{Error, RFCatDevice};
+use whiskers::registers;
+use whiskers::{CCRegisters, FirmwareImage, ProfileFormat, RadioProfile, RADIO_CONFIG_LEN};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// set (to anything) to run against an emulated CC instead of USB, like --mock
+const MOCK_ENV: &str = "WHISKERS_MOCK";
+
+fn mock_requested(matches: &clap::ArgMatches) -> bool {
+    matches.is_present("mock") || std::env::var_os(MOCK_ENV).is_some()
+}
+
+/* no libusb at all when running against the mock */
+fn usb_context(matches: &clap::ArgMatches) -> Option<libusb::Context> {
+    match mock_requested(matches) {
+        true => None,
+        false => Some(libusb::Context::new().unwrap()),
+    }
+}
+
+/* rfcat_filter, or a single emulated YARD Stick One under --mock */
+fn select_rfcats<'a>(
+    matches: &clap::ArgMatches,
+    context: Option<&'a libusb::Context>,
+    usb_addr: Option<Vec<&str>>,
+    usb_vp: Option<Vec<&str>>,
+) -> Result<Vec<RFCatDevice<'a>>, Error> {
+    if mock_requested(matches) {
+        return mock_rfcats();
+    }
+    let opened = rfcat_filter(context, usb_addr, usb_vp)?;
+    // the ones that won't open get a line each; the rest carry on
+    for failure in opened.failed.iter() {
+        println!("Error: RFCat: {}", failure);
+    }
+    Ok(opened.devices)
+}
+
+#[cfg(feature = "mock")]
+fn mock_rfcats<'a>() -> Result<Vec<RFCatDevice<'a>>, Error> {
+    Ok(vec![RFCatDevice::from_transport(Box::new(whiskers::mock::MockTransport::new()))])
+}
+
+#[cfg(not(feature = "mock"))]
+fn mock_rfcats<'a>() -> Result<Vec<RFCatDevice<'a>>, Error> {
+    Err(Error::Unsupported("--mock needs whiskers built with the mock feature"))
+}
+
+/* a register snapshot, one "<addr> <value> <name>" line per register */
+fn write_snapshot(path: &str, base: u16, data: &[u8]) -> std::io::Result<()> {
+    let mut text = String::new();
+    for reg in registers::radio() {
+        let addr = reg.xdata.unwrap();
+        if let Some(value) = data.get((addr - base) as usize) {
+            text.push_str(&format!("0x{:04x} 0x{:02x} {}\n", addr, value, reg.name));
+        }
+    }
+    std::fs::write(path, text)
+}
+
+fn read_snapshot(path: &str) -> Result<HashMap<u16, u8>, String> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) => {
+            return Err(format!("{}: {}", path, err));
+        },
+    };
+    let mut snapshot = HashMap::<u16, u8>::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue
+        }
+        let mut words = line.split_whitespace();
+        let addr = words.next().and_then(|w| u16::from_str_radix(w.trim_start_matches("0x"), 16).ok());
+        let value = words.next().and_then(|w| u8::from_str_radix(w.trim_start_matches("0x"), 16).ok());
+        match (addr, value) {
+            (Some(addr), Some(value)) => {
+                snapshot.insert(addr, value);
+            },
+            _ => {
+                return Err(format!("{}: not a register snapshot line: {:?}", path, line));
+            },
+        }
+    }
+    Ok(snapshot)
+}
+
+/* an XDATA address: hex (0x optional) or a register name */
+fn parse_addr(text: &str) -> Result<u16, String> {
+    if let Some(reg) = registers::by_name(text) {
+        return match reg.xdata {
+            Some(addr) => Ok(addr),
+            None => Err(format!("{} has no XDATA address", reg.name)),
+        };
+    }
+    match u16::from_str_radix(text.trim_start_matches("0x"), 16) {
+        Ok(addr) => Ok(addr),
+        Err(_) => Err(format!("not an address or register name: {:?}", text)),
+    }
+}
+
+/* a byte count: decimal, or hex with 0x */
+fn parse_len(text: &str) -> Result<u16, String> {
+    let parsed = match text.starts_with("0x") {
+        true => u16::from_str_radix(&text[2..], 16),
+        false => text.parse::<u16>(),
+    };
+    match parsed {
+        Ok(len) => Ok(len),
+        Err(_) => Err(format!("not a length: {:?}", text)),
+    }
+}
+
+/* "de ad" / "dead" / "0xde 0xad" all come out as [0xde, 0xad] */
+fn parse_hex_bytes<'a, I: Iterator<Item = &'a str>>(words: I) -> Result<Vec<u8>, String> {
+    let mut data = Vec::<u8>::new();
+    for word in words {
+        let digits = word.trim_start_matches("0x");
+        if digits.is_empty() || digits.len() % 2 != 0 {
+            return Err(format!("not hex bytes: {:?}", word));
+        }
+        for i in (0..digits.len()).step_by(2) {
+            match digits.get(i..i + 2).and_then(|pair| u8::from_str_radix(pair, 16).ok()) {
+                Some(b) => data.push(b),
+                None => {
+                    return Err(format!("not hex bytes: {:?}", word));
+                },
+            }
+        }
+    }
+    Ok(data)
+}
+
+/* --format if given, else whatever the file name says */
+fn profile_format(argm: &clap::ArgMatches, path: &str) -> ProfileFormat {
+    match argm.value_of("format").and_then(ProfileFormat::from_name) {
+        Some(format) => format,
+        None => ProfileFormat::from_path(path),
+    }
+}
+
+/* 16 bytes a line, prefixed with the address of the first */
+fn print_hex_dump(addr: u16, data: &[u8]) {
+    for (i, line) in data.chunks(16).enumerate() {
+        let bytes: Vec<String> = line.iter().map(|b| format!("{:02x}", b)).collect();
+        println!("  {:04x}: {}", addr as usize + i * 16, bytes.join(" "));
+    }
+}
 
 fn main() {
     let matches = App::new("whiskers")
         .version("0.2.0")
         .author("Dave Carlson <thecubic@thecubic.net>")
         .about("RFCat driver application")
+        .arg(Arg::with_name("mock")
+            .help("talk to an emulated CC instead of USB (for testing)")
+            .long("mock")
+            .hidden(true)
+            .required(false))
         .subcommand(
             SubCommand::with_name("list")
                 .about("list USB-attached RFCats"))
@@ -84,6 +238,15 @@ fn main() {
                     .help("select all USB addresses")
                     .long("all-usb")
                     .long("usb-all")
+                    .required(false))
+                .arg(Arg::with_name("wait")
+                    .help("wait for the stick(s) to come back in bootloader mode")
+                    .long("wait")
+                    .required(false))
+                .arg(Arg::with_name("flash")
+                    .help("then flash this Intel HEX image and go back to the application")
+                    .long("flash")
+                    .takes_value(true)
                     .required(false)))
         .subcommand(
             SubCommand::with_name("ping")
@@ -110,8 +273,73 @@ fn main() {
                     .long("usb-all")
                     .required(false)))
         .subcommand(
-            SubCommand::with_name("peektest")
-                .about("peek the device(s)")
+            SubCommand::with_name("peek")
+                .about("read and hex dump XDATA from the device(s)")
+                .arg(Arg::with_name("addr")
+                    .help("address (hex) or register name")
+                    .index(1)
+                    .required(true))
+                .arg(Arg::with_name("len")
+                    .help("bytes to read (default 1)")
+                    .index(2)
+                    .required(false))
+                .arg(Arg::with_name("usb-vp")
+                    .help("select specific USB vendor & product combos (format: <vendor hex>,<product hex>)")
+                    .long("usb-vp")
+                    .takes_value(true)
+                    .multiple(true)
+                    .required(false))
+                .group(ArgGroup::with_name("usb-select")
+                    .required(true)
+                    .arg("all-usb")
+                    .arg("usb-addr"))
+                .arg(Arg::with_name("usb-addr")
+                    .help("select specific USB addresses (format: <bus nbr>,<dev nbr>)")
+                    .long("usb-addr")
+                    .takes_value(true)
+                    .multiple(true)
+                    .required(false))
+                .arg(Arg::with_name("all-usb")
+                    .help("select all USB addresses")
+                    .long("all-usb")
+                    .long("usb-all")
+                    .required(false)))
+        .subcommand(
+            SubCommand::with_name("poke")
+                .about("write bytes into XDATA on the device(s)")
+                .arg(Arg::with_name("addr")
+                    .help("address (hex) or register name")
+                    .index(1)
+                    .required(true))
+                .arg(Arg::with_name("bytes")
+                    .help("hex bytes to write")
+                    .index(2)
+                    .multiple(true)
+                    .required(true))
+                .arg(Arg::with_name("usb-vp")
+                    .help("select specific USB vendor & product combos (format: <vendor hex>,<product hex>)")
+                    .long("usb-vp")
+                    .takes_value(true)
+                    .multiple(true)
+                    .required(false))
+                .group(ArgGroup::with_name("usb-select")
+                    .required(true)
+                    .arg("all-usb")
+                    .arg("usb-addr"))
+                .arg(Arg::with_name("usb-addr")
+                    .help("select specific USB addresses (format: <bus nbr>,<dev nbr>)")
+                    .long("usb-addr")
+                    .takes_value(true)
+                    .multiple(true)
+                    .required(false))
+                .arg(Arg::with_name("all-usb")
+                    .help("select all USB addresses")
+                    .long("all-usb")
+                    .long("usb-all")
+                    .required(false)))
+        .subcommand(
+            SubCommand::with_name("regs")
+                .about("dump the radio registers, decoded")
                 .arg(Arg::with_name("usb-vp")
                     .help("select specific USB vendor & product combos (format: <vendor hex>,<product hex>)")
                     .long("usb-vp")
@@ -132,7 +360,93 @@ fn main() {
                     .help("select all USB addresses")
                     .long("all-usb")
                     .long("usb-all")
+                    .required(false))
+                .arg(Arg::with_name("save")
+                    .help("also save a snapshot of the registers to this file")
+                    .long("save")
+                    .takes_value(true)
+                    .required(false))
+                .arg(Arg::with_name("diff")
+                    .help("mark the registers that changed since a saved snapshot")
+                    .long("diff")
+                    .takes_value(true)
                     .required(false)))
+        .subcommand(
+            SubCommand::with_name("config")
+                .about("save and load radio profiles (TOML, JSON for *.json, SmartRF Studio for *.h, or rfcat byte strings)")
+                .subcommand(
+                    SubCommand::with_name("save")
+                        .about("save the device's radio config to a profile")
+                        .arg(Arg::with_name("file")
+                            .help("profile to write")
+                            .index(1)
+                            .required(true))
+                        .arg(Arg::with_name("name")
+                            .help("name to put in the profile")
+                            .long("name")
+                            .takes_value(true)
+                            .required(false))
+                        .arg(Arg::with_name("format")
+                            .help("toml, json, smartrf (SmartRF Studio register export) or rfcat (RadioConfig byte string); default from the file name")
+                            .long("format")
+                            .takes_value(true)
+                            .possible_values(&["toml", "json", "smartrf", "rfcat"])
+                            .required(false))
+                        .arg(Arg::with_name("usb-vp")
+                            .help("select specific USB vendor & product combos (format: <vendor hex>,<product hex>)")
+                            .long("usb-vp")
+                            .takes_value(true)
+                            .multiple(true)
+                            .required(false))
+                        .group(ArgGroup::with_name("usb-select")
+                            .required(true)
+                            .arg("all-usb")
+                            .arg("usb-addr"))
+                        .arg(Arg::with_name("usb-addr")
+                            .help("select specific USB addresses (format: <bus nbr>,<dev nbr>)")
+                            .long("usb-addr")
+                            .takes_value(true)
+                            .multiple(true)
+                            .required(false))
+                        .arg(Arg::with_name("all-usb")
+                            .help("select all USB addresses")
+                            .long("all-usb")
+                            .long("usb-all")
+                            .required(false)))
+                .subcommand(
+                    SubCommand::with_name("load")
+                        .about("apply a profile to the device(s)")
+                        .arg(Arg::with_name("file")
+                            .help("profile to read")
+                            .index(1)
+                            .required(true))
+                        .arg(Arg::with_name("format")
+                            .help("toml, json, smartrf (SmartRF Studio register export) or rfcat (RadioConfig byte string); default from the file name")
+                            .long("format")
+                            .takes_value(true)
+                            .possible_values(&["toml", "json", "smartrf", "rfcat"])
+                            .required(false))
+                        .arg(Arg::with_name("usb-vp")
+                            .help("select specific USB vendor & product combos (format: <vendor hex>,<product hex>)")
+                            .long("usb-vp")
+                            .takes_value(true)
+                            .multiple(true)
+                            .required(false))
+                        .group(ArgGroup::with_name("usb-select")
+                            .required(true)
+                            .arg("all-usb")
+                            .arg("usb-addr"))
+                        .arg(Arg::with_name("usb-addr")
+                            .help("select specific USB addresses (format: <bus nbr>,<dev nbr>)")
+                            .long("usb-addr")
+                            .takes_value(true)
+                            .multiple(true)
+                            .required(false))
+                        .arg(Arg::with_name("all-usb")
+                            .help("select all USB addresses")
+                            .long("all-usb")
+                            .long("usb-all")
+                            .required(false))))
         .subcommand(
             SubCommand::with_name("has-bootloader")
                 .about("tests for CC-Bootloader")
@@ -163,7 +477,7 @@ fn main() {
         Some("buildname") => {
             let argm = matches.subcommand_matches("buildname").unwrap();
             /* TODO: for SPI-et-al support, USB must be optional */
-            let context = libusb::Context::new().unwrap();
+            let context = usb_context(&matches);
             
             let usb_vp = match argm.is_present("usb-vp") {
                 false => None,
@@ -175,7 +489,13 @@ fn main() {
                 true => Some(argm.values_of("usb-addr").unwrap().collect()),
             };
 
-            let rfcats = rfcat_filter(Some(&context), usb_addr, usb_vp);
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
             for rfcat in rfcats.iter() {
                 println!("RFCat: b{:03} d{:03}d v{:04x} p{:04x}",
                          rfcat.bus_number,
@@ -192,7 +512,7 @@ fn main() {
         Some("compiler") => {
             let argm = matches.subcommand_matches("compiler").unwrap();
             /* TODO: for SPI-et-al support, USB must be optional */
-            let context = libusb::Context::new().unwrap();
+            let context = usb_context(&matches);
             
             let usb_vp = match argm.is_present("usb-vp") {
                 false => None,
@@ -204,7 +524,13 @@ fn main() {
                 true => Some(argm.values_of("usb-addr").unwrap().collect()),
             };
 
-            let rfcats = rfcat_filter(Some(&context), usb_addr, usb_vp);
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
             for rfcat in rfcats.iter() {
                 println!("RFCat: b{:03} d{:03}d v{:04x} p{:04x}",
                          rfcat.bus_number,
@@ -219,9 +545,9 @@ fn main() {
             }
         },
         Some("bootloader") => {
-            let argm = matches.subcommand_matches("ping").unwrap();
+            let argm = matches.subcommand_matches("bootloader").unwrap();
             /* TODO: for SPI-et-al support, USB must be optional */
-            let context = libusb::Context::new().unwrap();
+            let context = usb_context(&matches);
             
             let usb_vp = match argm.is_present("usb-vp") {
                 false => None,
@@ -233,27 +559,85 @@ fn main() {
                 true => Some(argm.values_of("usb-addr").unwrap().collect()),
             };
 
-            let rfcats = rfcat_filter(Some(&context), usb_addr, usb_vp);
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+            let image = match argm.value_of("flash") {
+                None => None,
+                Some(hexfile) => match std::fs::read_to_string(hexfile) {
+                    Ok(text) => match FirmwareImage::parse(&text) {
+                        Ok(image) => Some(image),
+                        Err(err) => {
+                            println!("Error: {}: {}", hexfile, err);
+                            return;
+                        },
+                    },
+                    Err(err) => {
+                        println!("Error: {}: {}", hexfile, err);
+                        return;
+                    },
+                },
+            };
+
             for rfcat in rfcats.iter() {
                 println!("RFCat: b{:03} d{:03} v{:04x} p{:04x}",
                          rfcat.bus_number,
                          rfcat.address,
                          rfcat.vendor_id,
                          rfcat.product_id);
-                match rfcat.bootloader() {
-                    Ok(oktho) => {
-                        println!("  {}", oktho);
-                    },
+                if !argm.is_present("wait") && image.is_none() {
+                    match rfcat.bootloader() {
+                        Ok(oktho) => {
+                            println!("  {}", oktho);
+                        },
+                        Err(err) => {
+                            println!("  Error: {}", err);
+                        },
+                    }
+                    continue
+                }
+                let mut rfcatbl = match rfcat.enter_bootloader(Duration::from_secs(10)) {
+                    Ok(rfcatbl) => rfcatbl,
                     Err(err) => {
                         println!("  Error: {}", err);
+                        continue
                     },
+                };
+                println!("  RFCatBL: b{:03} d{:03} v{:04x} p{:04x}",
+                         rfcatbl.bus_number,
+                         rfcatbl.address,
+                         rfcatbl.vendor_id,
+                         rfcatbl.product_id);
+                if let Some(image) = &image {
+                    let progress = |p: whiskers::FlashProgress| {
+                        println!("  page {:2} ({}/{})", p.page, p.done, p.total);
+                    };
+                    match rfcatbl.flash(image, progress) {
+                        Ok(()) => (),
+                        Err(err) => {
+                            println!("  Error: {}", err);
+                            continue
+                        },
+                    }
+                    match rfcatbl.run() {
+                        Ok(()) => {
+                            println!("  flashed, back to the application");
+                        },
+                        Err(err) => {
+                            println!("  Error: {}", err);
+                        },
+                    }
                 }
             }
         },
         Some("ping") => {
             let argm = matches.subcommand_matches("ping").unwrap();
             /* TODO: for SPI-et-al support, USB must be optional */
-            let context = libusb::Context::new().unwrap();
+            let context = usb_context(&matches);
             
             let usb_vp = match argm.is_present("usb-vp") {
                 false => None,
@@ -265,7 +649,13 @@ fn main() {
                 true => Some(argm.values_of("usb-addr").unwrap().collect()),
             };
 
-            let rfcats = rfcat_filter(Some(&context), usb_addr, usb_vp);
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
 
             for rfcat in rfcats.iter() {
                 let pre = Instant::now();
@@ -284,11 +674,11 @@ fn main() {
                 }
             }
         },
-        Some("peektest") => {
+        Some("peek") => {
             let argm = matches.subcommand_matches("peek").unwrap();
             /* TODO: for SPI-et-al support, USB must be optional */
-            let context = libusb::Context::new().unwrap();
-            
+            let context = usb_context(&matches);
+
             let usb_vp = match argm.is_present("usb-vp") {
                 false => None,
                 true => Some(argm.values_of("usb-vp").unwrap().collect()),
@@ -299,21 +689,93 @@ fn main() {
                 true => Some(argm.values_of("usb-addr").unwrap().collect()),
             };
 
-            let rfcats = rfcat_filter(Some(&context), usb_addr, usb_vp);
+            let addr = match parse_addr(argm.value_of("addr").unwrap()) {
+                Ok(addr) => addr,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+            let len = match argm.value_of("len").map(parse_len) {
+                None => 1,
+                Some(Ok(len)) => len,
+                Some(Err(err)) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
 
             for rfcat in rfcats.iter() {
-                let pre = Instant::now();
                 println!("RFCat: b{:03} d{:03} v{:04x} p{:04x}",
                          rfcat.bus_number,
                          rfcat.address,
                          rfcat.vendor_id,
                          rfcat.product_id);
-                match rfcat.peek(0xDF46, 2) {
+                match rfcat.peek(addr, len) {
                     Ok(data) => {
-                        println!("  {} ({} us)", data.len(), pre.elapsed().as_micros());
-                        if data.len() == 2 {
-                            println!("  {} {}", data[0], data[1]);
-                        }
+                        print_hex_dump(addr, &data[..]);
+                    },
+                    Err(err) => {
+                        println!("  Error: {}", err);
+                    },
+                }
+            }
+        },
+        Some("poke") => {
+            let argm = matches.subcommand_matches("poke").unwrap();
+            /* TODO: for SPI-et-al support, USB must be optional */
+            let context = usb_context(&matches);
+
+            let usb_vp = match argm.is_present("usb-vp") {
+                false => None,
+                true => Some(argm.values_of("usb-vp").unwrap().collect()),
+            };
+
+            let usb_addr = match argm.is_present("usb-addr") {
+                false => None,
+                true => Some(argm.values_of("usb-addr").unwrap().collect()),
+            };
+
+            let addr = match parse_addr(argm.value_of("addr").unwrap()) {
+                Ok(addr) => addr,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+            let data = match parse_hex_bytes(argm.values_of("bytes").unwrap()) {
+                Ok(data) => data,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+
+            for rfcat in rfcats.iter() {
+                println!("RFCat: b{:03} d{:03} v{:04x} p{:04x}",
+                         rfcat.bus_number,
+                         rfcat.address,
+                         rfcat.vendor_id,
+                         rfcat.product_id);
+                match rfcat.poke(addr, &data[..]) {
+                    Ok(()) => {
+                        println!("  wrote {} bytes at {:04x}", data.len(), addr);
                     },
                     Err(err) => {
                         println!("  Error: {}", err);
@@ -321,10 +783,222 @@ fn main() {
                 }
             }
         },
+        Some("regs") => {
+            let argm = matches.subcommand_matches("regs").unwrap();
+            /* TODO: for SPI-et-al support, USB must be optional */
+            let context = usb_context(&matches);
+
+            let usb_vp = match argm.is_present("usb-vp") {
+                false => None,
+                true => Some(argm.values_of("usb-vp").unwrap().collect()),
+            };
+
+            let usb_addr = match argm.is_present("usb-addr") {
+                false => None,
+                true => Some(argm.values_of("usb-addr").unwrap().collect()),
+            };
+
+            let snapshot = match argm.value_of("diff") {
+                None => None,
+                Some(path) => match read_snapshot(path) {
+                    Ok(snapshot) => Some(snapshot),
+                    Err(err) => {
+                        println!("Error: {}", err);
+                        return;
+                    },
+                },
+            };
+
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
+            if argm.is_present("save") && rfcats.len() != 1 {
+                println!("Error: --save needs exactly one RFCat, selected {}", rfcats.len());
+                return;
+            }
+
+            let base = CCRegisters::Sync1 as u16;
+            for rfcat in rfcats.iter() {
+                println!("RFCat: b{:03} d{:03} v{:04x} p{:04x}",
+                         rfcat.bus_number,
+                         rfcat.address,
+                         rfcat.vendor_id,
+                         rfcat.product_id);
+                let data = match rfcat.peek(base, RADIO_CONFIG_LEN) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        println!("  Error: {}", err);
+                        continue
+                    },
+                };
+                let mut changed = 0;
+                for reg in registers::radio() {
+                    let addr = reg.xdata.unwrap();
+                    let value = match data.get((addr - base) as usize) {
+                        Some(value) => *value,
+                        None => continue,
+                    };
+                    let old = snapshot.as_ref().and_then(|s| s.get(&addr)).cloned();
+                    let mark = match old {
+                        Some(old) if old != value => {
+                            changed += 1;
+                            "*"
+                        },
+                        _ => " ",
+                    };
+                    let line = format!("{} 0x{:04x} {:<12} 0x{:02x}  {}", mark, addr, reg.name, value, reg.decode(value).join(", "));
+                    match old {
+                        Some(old) if old != value => {
+                            println!("{}  (was 0x{:02x}: {})", line.trim_end(), old, reg.decode(old).join(", "));
+                        },
+                        _ => println!("{}", line.trim_end()),
+                    }
+                }
+                if snapshot.is_some() {
+                    println!("  {} changed", changed);
+                }
+                if let Some(path) = argm.value_of("save") {
+                    match write_snapshot(path, base, &data[..]) {
+                        Ok(()) => {
+                            println!("  saved to {}", path);
+                        },
+                        Err(err) => {
+                            println!("  Error: {}: {}", path, err);
+                        },
+                    }
+                }
+            }
+        },
+        Some("config") => {
+            let configm = matches.subcommand_matches("config").unwrap();
+            match configm.subcommand_name() {
+                Some("save") => {
+                    let argm = configm.subcommand_matches("save").unwrap();
+                    /* TODO: for SPI-et-al support, USB must be optional */
+                    let context = usb_context(&matches);
+
+                    let usb_vp = match argm.is_present("usb-vp") {
+                        false => None,
+                        true => Some(argm.values_of("usb-vp").unwrap().collect()),
+                    };
+
+                    let usb_addr = match argm.is_present("usb-addr") {
+                        false => None,
+                        true => Some(argm.values_of("usb-addr").unwrap().collect()),
+                    };
+
+                    let path = argm.value_of("file").unwrap();
+
+                    let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                        Ok(rfcats) => rfcats,
+                        Err(err) => {
+                            println!("Error: {}", err);
+                            return;
+                        },
+                    };
+                    if rfcats.len() != 1 {
+                        println!("Error: config save needs exactly one RFCat, selected {}", rfcats.len());
+                        return;
+                    }
+
+                    for rfcat in rfcats.iter() {
+                        println!("RFCat: b{:03} d{:03} v{:04x} p{:04x}",
+                                 rfcat.bus_number,
+                                 rfcat.address,
+                                 rfcat.vendor_id,
+                                 rfcat.product_id);
+                        let config = match rfcat.get_radio_config() {
+                            Ok(config) => config,
+                            Err(err) => {
+                                println!("  Error: {}", err);
+                                continue
+                            },
+                        };
+                        let mut profile = config.to_profile();
+                        profile.name = argm.value_of("name").map(String::from);
+                        let text = match config.export(profile_format(argm, path), argm.value_of("name")) {
+                            Ok(text) => text,
+                            Err(err) => {
+                                println!("  Error: {}", err);
+                                continue
+                            },
+                        };
+                        match std::fs::write(path, text) {
+                            Ok(()) => {
+                                println!("  {}", profile);
+                                println!("  saved to {}", path);
+                            },
+                            Err(err) => {
+                                println!("  Error: {}: {}", path, err);
+                            },
+                        }
+                    }
+                },
+                Some("load") => {
+                    let argm = configm.subcommand_matches("load").unwrap();
+                    /* TODO: for SPI-et-al support, USB must be optional */
+                    let context = usb_context(&matches);
+
+                    let usb_vp = match argm.is_present("usb-vp") {
+                        false => None,
+                        true => Some(argm.values_of("usb-vp").unwrap().collect()),
+                    };
+
+                    let usb_addr = match argm.is_present("usb-addr") {
+                        false => None,
+                        true => Some(argm.values_of("usb-addr").unwrap().collect()),
+                    };
+
+                    let path = argm.value_of("file").unwrap();
+                    let profile = match std::fs::read_to_string(path) {
+                        Ok(text) => match RadioProfile::parse(&text, profile_format(argm, path)) {
+                            Ok(profile) => profile,
+                            Err(err) => {
+                                println!("Error: {}: {}", path, err);
+                                return;
+                            },
+                        },
+                        Err(err) => {
+                            println!("Error: {}: {}", path, err);
+                            return;
+                        },
+                    };
+
+                    let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                        Ok(rfcats) => rfcats,
+                        Err(err) => {
+                            println!("Error: {}", err);
+                            return;
+                        },
+                    };
+
+                    for rfcat in rfcats.iter() {
+                        println!("RFCat: b{:03} d{:03} v{:04x} p{:04x}",
+                                 rfcat.bus_number,
+                                 rfcat.address,
+                                 rfcat.vendor_id,
+                                 rfcat.product_id);
+                        match rfcat.update_radio_config(|config| config.apply_profile(&profile)) {
+                            Ok(()) => {
+                                println!("  loaded {}: {}", path, profile);
+                            },
+                            Err(err) => {
+                                println!("  Error: {}", err);
+                            },
+                        }
+                    }
+                },
+                _ => println!("{}", configm.usage()),
+            }
+        },
         Some("has-bootloader") => {
             let argm = matches.subcommand_matches("has-bootloader").unwrap();
             /* TODO: for SPI-et-al support, USB must be optional */
-            let context = libusb::Context::new().unwrap();
+            let context = usb_context(&matches);
             
             let usb_vp = match argm.is_present("usb-vp") {
                 false => None,
@@ -336,7 +1010,13 @@ fn main() {
                 true => Some(argm.values_of("usb-addr").unwrap().collect()),
             };
 
-            let rfcats = rfcat_filter(Some(&context), usb_addr, usb_vp);
+            let rfcats = match select_rfcats(&matches, context.as_ref(), usb_addr, usb_vp) {
+                Ok(rfcats) => rfcats,
+                Err(err) => {
+                    println!("Error: {}", err);
+                    return;
+                },
+            };
 
             for rfcat in rfcats.iter() {
                 let pre = Instant::now();
@@ -1,13 +1,49 @@
 extern crate libusb;
 extern crate queues;
+extern crate serde;
+extern crate serde_json;
+extern crate toml;
 
-use std::slice;
-use std::time::Duration;
+use std::cell::{Cell, RefCell};
+use std::thread;
+use std::time::{Duration, Instant};
 use std::convert::From;
-use std::error::Error;
+use std::fmt;
 use std::collections::HashSet;
 use std::collections::HashMap;
-use queues::Queue;
+use std::collections::VecDeque;
+use queues::{IsQueue, Queue};
+
+#[path = "lib/error.rs"]
+pub mod error;
+#[path = "lib/transport.rs"]
+pub mod transport;
+#[path = "lib/usb.rs"]
+pub mod usb;
+#[cfg(any(test, feature = "mock"))]
+#[path = "lib/mock.rs"]
+pub mod mock;
+#[path = "lib/manager.rs"]
+pub mod manager;
+#[path = "lib/ihex.rs"]
+pub mod ihex;
+#[path = "lib/usbbl.rs"]
+pub mod usbbl;
+#[path = "lib/registers.rs"]
+pub mod registers;
+#[path = "lib/profile.rs"]
+pub mod profile;
+#[path = "lib/interop.rs"]
+pub mod interop;
+
+pub use error::Error;
+pub use transport::{Transport, TransportIdentity};
+pub use usb::{shared_context, UsbTransport};
+pub use manager::{DeviceEvent, DeviceKind, DeviceManager};
+pub use ihex::{FirmwareImage, HexRecord};
+pub use usbbl::{BootloaderStatus, FlashProgress};
+pub use registers::{Field, FieldValue, Register};
+pub use profile::{ProfileFormat, RadioProfile};
 
 // #[allow(dead_code)]
 
@@ -31,6 +67,7 @@ pub enum SystemCommand {
 }
 
 // RFST (0xE1) - RF Strobe Commands
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub enum RfState {
     SFSTXON = 0x00,
     SCAL = 0x01,
@@ -82,9 +119,10 @@ pub enum Addresses {
     RfState = 0xDFE1,
 }
 
-#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub enum AppMailbox {
 	AppGeneric = 0x01,
+	AppNic = 0x42,
 	AppDebug = 0xfe,
 	AppSystem = 0xff,
     UNKNOWN = 0x00,
@@ -95,6 +133,7 @@ impl From<u8> for AppMailbox {
         // this is so boilerplatey There Has To Be Another Way![TM]
         match value {
             0x01 => AppMailbox::AppGeneric,
+            0x42 => AppMailbox::AppNic,
             0xfe => AppMailbox::AppDebug,
             0xff => AppMailbox::AppSystem,
             _ => AppMailbox::UNKNOWN,
@@ -102,6 +141,86 @@ impl From<u8> for AppMailbox {
     }
 }
 
+// commands understood by the RfCat NIC application (AppMailbox::AppNic)
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum NicCommand {
+    Recv = 0x01,
+    Xmit = 0x02,
+    SetId = 0x03,
+    SetRecvLarge = 0x05,
+    SetAesMode = 0x06,
+    GetAesMode = 0x07,
+    SetAesIv = 0x08,
+    SetAesKey = 0x09,
+    SetAmpMode = 0x0a,
+    GetAmpMode = 0x0b,
+    LongXmit = 0x0c,
+    LongXmitMore = 0x0d,
+    UNKNOWN = 0x00,
+}
+
+impl From<u8> for NicCommand {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => NicCommand::Recv,
+            0x02 => NicCommand::Xmit,
+            0x03 => NicCommand::SetId,
+            0x05 => NicCommand::SetRecvLarge,
+            0x06 => NicCommand::SetAesMode,
+            0x07 => NicCommand::GetAesMode,
+            0x08 => NicCommand::SetAesIv,
+            0x09 => NicCommand::SetAesKey,
+            0x0a => NicCommand::SetAmpMode,
+            0x0b => NicCommand::GetAmpMode,
+            0x0c => NicCommand::LongXmit,
+            0x0d => NicCommand::LongXmitMore,
+            _ => NicCommand::UNKNOWN,
+        }
+    }
+}
+
+// return codes the NIC application hands back for XMIT / LONG_XMIT
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum NicReturnCode {
+    NoError = 0x00,
+    TxDroppedPacket = 0xec,
+    TxError = 0xed,
+    RfBlocksizeIncompat = 0xee,
+    RfModeIncompat = 0xef,
+    TempErrBufferNotAvailable = 0xfe,
+    ErrBufferSizeExceeded = 0xff,
+    UNKNOWN = 0x01,
+}
+
+impl From<u8> for NicReturnCode {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => NicReturnCode::NoError,
+            0xec => NicReturnCode::TxDroppedPacket,
+            0xed => NicReturnCode::TxError,
+            0xee => NicReturnCode::RfBlocksizeIncompat,
+            0xef => NicReturnCode::RfModeIncompat,
+            0xfe => NicReturnCode::TempErrBufferNotAvailable,
+            0xff => NicReturnCode::ErrBufferSizeExceeded,
+            _ => NicReturnCode::UNKNOWN,
+        }
+    }
+}
+
+impl NicReturnCode {
+    fn into_result(self) -> Result<(), Error> {
+        match self {
+            NicReturnCode::NoError => Ok(()),
+            code => Err(Error::Firmware(code)),
+        }
+    }
+}
+
+// firmware transmit buffer limits
+pub const RF_MAX_TX_BLOCK: usize = 255;
+pub const RF_MAX_TX_CHUNK: usize = 240;
+pub const RF_MAX_TX_LONG: usize = 65535;
+
 pub enum CCRegisters {
     Sync1      = 0xdf00,
     Sync0      = 0xdf01,
@@ -167,59 +286,144 @@ pub enum CCRegisters {
     VcoVcDac = 0xdf3d,
 }
 
+// packets kept per (mailbox, command) before the oldest get dropped
+const MAILBOX_QUEUE_DEPTH: usize = 256;
+// how often wait_for_state looks at MARCSTATE
+const MARCSTATE_POLL_INTERVAL: Duration = Duration::from_millis(1);
+// idling, calibrating or settling into RX/TX takes the CC well under this
+const STATE_CHANGE_TIMEOUT: Duration = Duration::from_millis(500);
+
+// breather between LONG_XMIT_MORE retries while the CC's TX buffers are full
+const TX_BUFFER_BACKOFF: Duration = Duration::from_millis(2);
+
+// how often to look for a stick that's re-enumerating
+const REENUMERATE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/* size of the radio register block, and how much of it is writable */
+pub const RADIO_CONFIG_LEN: u16 = 0x3e;
+pub const RADIO_CONFIG_WRITABLE_LEN: u16 = 0x32;
+
+// biggest peek/poke we hand the firmware in one go; its EP5 buffers are ~0x200
+const XDATA_CHUNK_LEN: usize = 0x100;
+
+// devices on the shared context: owned, Send, good for the life of the process
+pub type OwnedRFCatDevice = RFCatDevice<'static>;
+pub type OwnedRFCatBLDevice = RFCatBLDevice<'static>;
 
 pub struct RFCatDevice<'a> {
     pub bus_number: u8,
     pub address: u8,
     pub vendor_id: u16,
     pub product_id: u16,
-    handle: libusb::DeviceHandle<'a>,
-    descriptor: libusb::DeviceDescriptor,
-    language: Option<libusb::Language>,
+    transport: Box<dyn Transport + 'a>,
+    // the mode set_rf_mode last asked for: what the radio goes back to after
+    // TX/RX, and what strobe_mode_return puts it back into
+    radio_mode: Cell<Option<RfState>>,
+    // set while inside with_idle, so nested calls don't un-idle the radio early
+    idling: Cell<bool>,
+    // last known PKTCTRL1 APPEND_STATUS, so recv_rf knows where RSSI/LQI live
+    append_status: Cell<Option<bool>>,
+    // last radio config read from or written to the CC, for putting it back after a replug
+    radio_config: Cell<Option<RadioConfig>>,
+    decoder: RefCell<FrameDecoder>,
+    // unsolicited / not-yet-wanted packets, by (mailbox, command)
+    mailbox_queues: RefCell<HashMap<(AppMailbox, u8), Queue<RfCatPacket>>>,
+}
+
+/* one packet off the air, as handed up by the NIC application */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RfFrame {
+    pub payload: Vec<u8>,
+    // raw RSSI register value (two's complement, half-dB steps)
+    pub rssi: u8,
+    // link quality indicator (LQI bits only)
+    pub lqi: u8,
+    pub crc_ok: bool,
+}
+
+// CC1111 datasheet RSSI offset
+const RSSI_OFFSET_DB: i16 = 74;
+
+impl RfFrame {
+    /* RSSI in dBm, using the datasheet's typical offset */
+    pub fn rssi_dbm(&self) -> i16 {
+        (self.rssi as i8) as i16 / 2 - RSSI_OFFSET_DB
+    }
+}
+
+/* yields RF frames until one doesn't show up within the timeout */
+pub struct RfFrames<'d, 'a> {
+    device: &'d RFCatDevice<'a>,
     timeout: Duration,
-    max_input_size: u16,
-    in_endpoint_address: u8,
-    out_endpoint_address: u8,
-    //
-    radio_mode: Option<RfState>,
-    mailbox_queues: HashMap<(AppMailbox, SystemCommand), Queue<RfCatPacket>>,
+    done: bool,
+}
+
+impl<'d, 'a> Iterator for RfFrames<'d, 'a> {
+    type Item = Result<RfFrame, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.device.recv_rf(self.timeout) {
+            Ok(frame) => Some(Ok(frame)),
+            Err(Error::Timeout) => {
+                self.done = true;
+                None
+            },
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            },
+        }
+    }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RfCatPacket {
-    pub cmd: SystemCommand,
+    // SystemCommand, NicCommand, ... depending on the mailbox
+    pub cmd: u8,
     pub mbx: AppMailbox,
     pub payload: Vec<u8>,
     pub received: bool,
 }
 
 impl<'a> RfCatPacket {
-    pub fn simple(mbx: AppMailbox, cmd: SystemCommand) -> RfCatPacket {
+    pub fn simple(mbx: AppMailbox, cmd: u8) -> RfCatPacket {
         RfCatPacket{mbx: mbx, cmd: cmd, payload: Vec::<u8>::new(), received: false}
     }
-    pub fn payload(mbx: AppMailbox, cmd: SystemCommand, payload: Vec<u8>) -> RfCatPacket {
+    pub fn payload(mbx: AppMailbox, cmd: u8, payload: Vec<u8>) -> RfCatPacket {
         RfCatPacket{mbx: mbx, cmd: cmd, payload: payload, received: false}
     }
 
-    pub fn from_bytes(bytes: Vec<u8>) -> RfCatPacket {
-        let plen: u16 = u16::from_le_bytes([bytes[3], bytes[4]]);
-        let mut rcvr: Vec<u8> = Vec::<u8>::with_capacity(plen as usize);
-        let payload_end: u16 = 5 + plen;
-        match payload_end {
-            0 => (),
-            _ => {
-                for offset in 5..payload_end {
-                    rcvr.push(bytes[offset as usize]);
-                }
-            }
+    /* parse exactly one complete '@' frame (see FrameDecoder for streams) */
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<RfCatPacket, FrameError> {
+        if bytes.len() < FRAME_HEADER_LEN {
+            return Err(FrameError::Truncated{expected: FRAME_HEADER_LEN, got: bytes.len()});
+        }
+        if bytes[0] != FRAME_SYNC {
+            return Err(FrameError::BadSync(bytes[0]));
+        }
+        let plen = u16::from_le_bytes([bytes[3], bytes[4]]) as usize;
+        let payload_end = FRAME_HEADER_LEN + plen;
+        if bytes.len() < payload_end {
+            return Err(FrameError::Truncated{expected: payload_end, got: bytes.len()});
         }
-        RfCatPacket{mbx: AppMailbox::from(bytes[1]), cmd: SystemCommand::from(bytes[2]), payload: rcvr, received: true}
+        if bytes.len() > payload_end {
+            return Err(FrameError::TrailingBytes(bytes.len() - payload_end));
+        }
+        Ok(RfCatPacket{
+            mbx: AppMailbox::from(bytes[1]),
+            cmd: bytes[2],
+            payload: bytes[FRAME_HEADER_LEN..payload_end].to_vec(),
+            received: true,
+        })
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut pktbytes = Vec::<u8>::with_capacity(self.payload.len() + 4);
         pktbytes.push(self.mbx as u8);
-        pktbytes.push(self.cmd as u8);
+        pktbytes.push(self.cmd);
         for b in (self.payload.len() as u16).to_le_bytes().iter() {
             pktbytes.push(*b);
         }
@@ -230,64 +434,248 @@ impl<'a> RfCatPacket {
     }
 }
 
+// CC -> host frames: '@' <mbx> <cmd> <len le16> <payload>
+const FRAME_SYNC: u8 = 0x40;
+const FRAME_HEADER_LEN: usize = 5;
+// nothing the firmware sends comes close; bigger means we locked onto garbage
+const FRAME_MAX_PAYLOAD: usize = 0x1000;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FrameError {
+    // first byte wasn't '@'
+    BadSync(u8),
+    // ran out of bytes before the header / payload was complete
+    Truncated { expected: usize, got: usize },
+    // more bytes than the header's length accounts for
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameError::BadSync(b) => write!(f, "bad frame sync byte 0x{:02x}", b),
+            FrameError::Truncated{expected, got} => write!(f, "truncated frame ({} of {} bytes)", got, expected),
+            FrameError::TrailingBytes(n) => write!(f, "{} trailing bytes after frame", n),
+        }
+    }
+}
+
+impl std::error::Error for FrameError {}
+
+/*
+ * streaming '@' frame decoder: feed it whatever each bulk read returned and
+ * it hands back whole packets, reassembling payloads that span reads and
+ * skipping over garbage until it finds a plausible header again
+ */
+#[derive(Default)]
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    skipped: usize,
+}
+
+impl FrameDecoder {
+    pub fn new() -> FrameDecoder {
+        FrameDecoder::default()
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /*
+     * next complete packet, if there is one buffered.  frames are cut by
+     * their length whatever the mailbox, so an unknown one still comes out
+     * whole (for the dispatcher to turn away) rather than resyncing into
+     * the middle of its payload.
+     */
+    pub fn next_packet(&mut self) -> Result<Option<RfCatPacket>, FrameError> {
+        loop {
+            self.resync();
+            if self.buffer.len() < FRAME_HEADER_LEN {
+                return Ok(None);
+            }
+            let plen = u16::from_le_bytes([self.buffer[3], self.buffer[4]]) as usize;
+            // an '@' followed by an absurd length is garbage, not a header
+            if plen > FRAME_MAX_PAYLOAD {
+                self.buffer.remove(0);
+                self.skipped += 1;
+                continue;
+            }
+            let frame_len = FRAME_HEADER_LEN + plen;
+            if self.buffer.len() < frame_len {
+                return Ok(None);
+            }
+            let rest = self.buffer.split_off(frame_len);
+            let frame = std::mem::replace(&mut self.buffer, rest);
+            return RfCatPacket::from_bytes(frame).map(Some);
+        }
+    }
+
+    /* bytes sitting in the buffer that aren't a whole packet yet */
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /* how many garbage bytes have been thrown away resyncing */
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /* drop everything, e.g. after the device went away */
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn resync(&mut self) {
+        let start = self.buffer.iter().position(|&b| b == FRAME_SYNC).unwrap_or(self.buffer.len());
+        if start > 0 {
+            self.buffer.drain(..start);
+            self.skipped += start;
+        }
+    }
+}
+
 impl<'a> RFCatDevice<'a> {
 
+    pub fn from_transport(transport: Box<dyn Transport + 'a>) -> RFCatDevice<'a> {
+        let ident = transport.identity();
+        RFCatDevice{
+            bus_number: ident.bus_number,
+            address: ident.address,
+            vendor_id: ident.vendor_id,
+            product_id: ident.product_id,
+            transport: transport,
+            radio_mode: Cell::new(None),
+            idling: Cell::new(false),
+            append_status: Cell::new(None),
+            radio_config: Cell::new(None),
+            decoder: RefCell::new(FrameDecoder::new()),
+            mailbox_queues: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn transport(&self) -> &dyn Transport {
+        &*self.transport
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.transport.timeout()
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.transport.set_timeout(timeout);
+    }
+
     /* USB layer */
-    pub fn manufacturer(&self) -> Result<String, libusb::Error> {
-        match self.handle.read_manufacturer_string(self.language.unwrap(), &self.descriptor, self.timeout) {
-            Ok(mstr) => {
-                return Ok(mstr);
-            },
-            Err(err) => {
-                return Err(err);
+    pub fn manufacturer(&self) -> Result<String, Error> {
+        self.transport.manufacturer()
+    }
+
+    pub fn product(&self) -> Result<String, Error> {
+        self.transport.product()
+    }
+
+    pub fn serial_number(&self) -> Result<String, Error> {
+        self.transport.serial_number()
+    }
+
+    /* CC layer */
+
+    /* send a command packet to the CC down the wire(s) */
+    pub fn mail(&self, pkt: RfCatPacket) -> Result<usize, Error> {
+        self.transport.send_frame(&pkt.to_bytes()[..])
+    }
+
+    /* raw, un-mailboxed receive (skips the mailbox queues entirely) */
+    pub fn recv(&self) -> Result<RfCatPacket, Error> {
+        self.read_packet(self.timeout())
+    }
+
+    /* next whole packet off the wire, reading (and reassembling) as much as it takes */
+    fn read_packet(&self, timeout: Duration) -> Result<RfCatPacket, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let next = self.decoder.borrow_mut().next_packet()?;
+            if let Some(pkt) = next {
+                return Ok(pkt);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::Timeout);
             }
+            let in_vec = self.transport.recv_frame_timeout(deadline - now)?;
+            self.decoder.borrow_mut().feed(&in_vec[..]);
         }
     }
 
-    pub fn product(&self) -> Result<String, libusb::Error> {
-        match self.handle.read_product_string(self.language.unwrap(), &self.descriptor, self.timeout) {
-            Ok(pstr) => {
-                return Ok(pstr);
-            },
-            Err(err) => {
-                return Err(err);
+    /* wait for the reply to (mbx, cmd), queueing whatever else turns up meanwhile */
+    pub fn recv_reply(&self, mbx: AppMailbox, cmd: u8) -> Result<RfCatPacket, Error> {
+        self.recv_mailbox(mbx, cmd, self.timeout())
+    }
+
+    /*
+     * the dispatcher: hand back the next packet for (mbx, cmd), either one
+     * that was queued earlier or the next one off the wire.  everything else
+     * read in the meantime goes into its own mailbox queue for later.
+     */
+    pub fn recv_mailbox(&self, mbx: AppMailbox, cmd: u8, timeout: Duration) -> Result<RfCatPacket, Error> {
+        if let Some(pkt) = self.dequeue(mbx, cmd) {
+            return Ok(pkt);
+        }
+        let deadline = Instant::now() + timeout;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::Timeout);
+            }
+            let pkt = self.read_packet(deadline - now)?;
+            // no application of ours lives there: nobody will ever dequeue it
+            if pkt.mbx == AppMailbox::UNKNOWN {
+                continue;
+            }
+            if pkt.mbx == mbx && pkt.cmd == cmd {
+                return Ok(pkt);
             }
+            self.enqueue(pkt);
         }
     }
 
-    /* CC layer */
+    /* next already-received packet for (mbx, cmd), if any */
+    pub fn dequeue(&self, mbx: AppMailbox, cmd: u8) -> Option<RfCatPacket> {
+        match self.mailbox_queues.borrow_mut().get_mut(&(mbx, cmd)) {
+            Some(queue) => queue.remove().ok(),
+            None => None,
+        }
+    }
 
-    /* send a command packet to the CC down the wire(s) */
-    pub fn mail(&self, pkt: RfCatPacket) -> Result<usize, libusb::Error> {
-        self.handle.write_bulk(self.out_endpoint_address, &pkt.to_bytes()[..], self.timeout)
+    /* how many packets are waiting in (mbx, cmd)'s queue */
+    pub fn queued(&self, mbx: AppMailbox, cmd: u8) -> usize {
+        match self.mailbox_queues.borrow().get(&(mbx, cmd)) {
+            Some(queue) => queue.size(),
+            None => 0,
+        }
     }
 
-    /* raw, un-mailboxed receive */
-    pub fn recv(&self) -> Result<RfCatPacket, libusb::Error> {
-        let mut in_vec = Vec::<u8>::with_capacity(self.max_input_size as usize);
-        let in_buf = unsafe { slice::from_raw_parts_mut((&mut in_vec[..]).as_mut_ptr(), in_vec.capacity()) };
-        match self.handle.read_bulk(self.in_endpoint_address, in_buf, self.timeout) {
-            Ok(rlen) => {
-                unsafe { in_vec.set_len(rlen) };
-                return Ok(RfCatPacket::from_bytes(in_vec));
-            },
-            Err(err) => {
-                println!("nope: {}", err);
-                return Err(err);
-            },
+    fn enqueue(&self, pkt: RfCatPacket) {
+        let mut queues = self.mailbox_queues.borrow_mut();
+        let queue = queues.entry((pkt.mbx, pkt.cmd)).or_default();
+        // nobody is listening: don't let it pile up forever
+        if queue.size() >= MAILBOX_QUEUE_DEPTH {
+            let _ = queue.remove();
         }
+        let _ = queue.add(pkt);
     }
 
     /* simple CC communication with no payload */
-    pub fn ping(&self) -> Result<bool, libusb::Error> {
-        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::Ping)) {
+    pub fn ping(&self) -> Result<bool, Error> {
+        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::Ping as u8)) {
             Ok(_) => (),
             Err(err) => {
                 return Err(err);
             },
         }
-        match self.recv() {
-            Ok(pkt) => {
+        match self.recv_reply(AppMailbox::AppSystem, SystemCommand::Ping as u8) {
+            Ok(_) => {
                 return Ok(true);
             },
             Err(err) => {
@@ -296,16 +684,35 @@ impl<'a> RFCatDevice<'a> {
         }
     }
 
-    /* tell the CC to escape into bootloader mode (requires CC bootloader) */
-    pub fn bootloader(&self) -> Result<bool, libusb::Error> {
-        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::Bootloader)) {
+    /*
+     * tell the CC to escape into bootloader mode (requires CC bootloader).
+     * it drops off the bus doing so, so losing it mid-reply is success too.
+     */
+    pub fn bootloader(&self) -> Result<bool, Error> {
+        match self.has_bootloader() {
+            Ok(true) => (),
+            Ok(false) => {
+                return Err(Error::Unsupported("no CC-Bootloader on this device"));
+            },
+            Err(err) => {
+                return Err(err);
+            },
+        }
+        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::Bootloader as u8)) {
             Ok(_) => (),
+            Err(Error::Disconnected) => {
+                return Ok(true);
+            },
             Err(err) => {
                 return Err(err);
             },
         }
-        match self.recv() {
-            Ok(pkt) => {
+        match self.recv_reply(AppMailbox::AppSystem, SystemCommand::Bootloader as u8) {
+            Ok(_) => {
+                return Ok(true);
+            },
+            Err(Error::Timeout) | Err(Error::Disconnected) |
+            Err(Error::Transport(libusb::Error::Io)) | Err(Error::Transport(libusb::Error::Pipe)) => {
                 return Ok(true);
             },
             Err(err) => {
@@ -314,18 +721,55 @@ impl<'a> RFCatDevice<'a> {
         }
     }
 
+    /*
+     * bootloader(), then wait up to timeout for the stick to come back as a
+     * CC-Bootloader device on the same bus, and hand that back
+     */
+    pub fn enter_bootloader(&self, timeout: Duration) -> Result<OwnedRFCatBLDevice, Error> {
+        let context = shared_context()?;
+        // bootloaders already around aren't ours
+        let mut before = HashSet::<(u8, u8)>::new();
+        for device in context.devices()?.iter() {
+            if let Ok(device_desc) = device.device_descriptor() {
+                if is_rfcat_bootloader(&device_desc) {
+                    before.insert((device.bus_number(), device.address()));
+                }
+            }
+        }
+        self.bootloader()?;
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            thread::sleep(REENUMERATE_POLL_INTERVAL);
+            for device in context.devices()?.iter() {
+                let device_desc = match device.device_descriptor() {
+                    Ok(k) => k,
+                    Err(_) => continue,
+                };
+                let key = (device.bus_number(), device.address());
+                if !is_rfcat_bootloader(&device_desc) || key.0 != self.bus_number || before.contains(&key) {
+                    continue
+                }
+                // may still be settling: if it won't open yet, try again next round
+                if let Ok(rfcatbl) = RFCatBLDevice::make_from_libusb(device, device_desc) {
+                    return Ok(rfcatbl);
+                }
+            }
+        }
+        Err(Error::Timeout)
+    }
+
     /* retrieve the CC firmware's build name if present (e.g. YARDSTICKONE r0543) */
-    pub fn buildname(&self) -> Result<Option<String>, libusb::Error> {
-        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::BuildType)) {
+    pub fn buildname(&self) -> Result<Option<String>, Error> {
+        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::BuildType as u8)) {
             Ok(_) => (),
             Err(err) => {
                 return Err(err);
             },
         }
-        match self.recv() {
+        match self.recv_reply(AppMailbox::AppSystem, SystemCommand::BuildType as u8) {
             Ok(pkt) => {
                 if pkt.payload.len() > 0 {
-                    return Ok(Some(String::from_utf8(pkt.payload).unwrap()));
+                    return Ok(Some(String::from_utf8(pkt.payload)?));
                 } else {
                     return Ok(None);
                 }
@@ -337,17 +781,17 @@ impl<'a> RFCatDevice<'a> {
     }
 
     /* retrieve the CC firmware's compiler name if present (e.g. SDCCv370) */
-    pub fn compiler(&self) -> Result<Option<String>, libusb::Error> {
-        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::Compiler)) {
+    pub fn compiler(&self) -> Result<Option<String>, Error> {
+        match self.mail(RfCatPacket::simple(AppMailbox::AppSystem, SystemCommand::Compiler as u8)) {
             Ok(_) => (),
             Err(err) => {
                 return Err(err);
             },
         }
-        match self.recv() {
+        match self.recv_reply(AppMailbox::AppSystem, SystemCommand::Compiler as u8) {
             Ok(pkt) => {
                 if pkt.payload.len() > 0 {
-                    return Ok(Some(String::from_utf8(pkt.payload).unwrap()));
+                    return Ok(Some(String::from_utf8(pkt.payload)?));
                 } else {
                     return Ok(None);
                 }
@@ -358,39 +802,41 @@ impl<'a> RFCatDevice<'a> {
         }
     }
 
-    pub fn reset(&self) -> Result<usize, libusb::Error> {
+    pub fn reset(&self) -> Result<usize, Error> {
         self.mail(RfCatPacket::payload(AppMailbox::AppSystem,
-                                       SystemCommand::Reset, 
+                                       SystemCommand::Reset as u8,
                                        "RESET_NOW\x00".as_bytes().to_vec()))
     }
 
-    pub fn peek(&self, addr: u16, bytecount: u16) -> Result<Vec<u8>, libusb::Error> {
-        // TODO: this is reeeal stupid
-        let bcle = bytecount.to_le_bytes();
-        let adle = addr.to_le_bytes();
-
-        match self.mail(RfCatPacket::payload(AppMailbox::AppSystem,
-                                             SystemCommand::Peek,
-                                             vec![bcle[0], bcle[1], adle[0], adle[1]])) {
-            Ok(_) => (),
-            Err(err) => {
-                return Err(err);
-            },
+    /* read bytecount bytes of XDATA starting at addr, a chunk at a time */
+    pub fn peek(&self, addr: u16, bytecount: u16) -> Result<Vec<u8>, Error> {
+        if addr as usize + bytecount as usize > 0x10000 {
+            return Err(Error::InvalidArgument(format!("peek of {} bytes at 0x{:04x} runs off the end of XDATA", bytecount, addr)));
         }
-        match self.recv() {
-            Ok(pkt) => {
-                return Ok(pkt.payload.to_owned());
-            },
-            Err(err) => {
-                return Err(err);
-            },
+        let mut out = Vec::<u8>::with_capacity(bytecount as usize);
+        while out.len() < bytecount as usize {
+            let chunk_addr = addr + out.len() as u16;
+            let chunk_len = std::cmp::min(XDATA_CHUNK_LEN, bytecount as usize - out.len());
+            // payload: <len le16> <addr le16>, CC answers with exactly len bytes
+            let mut payload = Vec::<u8>::with_capacity(4);
+            payload.extend_from_slice(&(chunk_len as u16).to_le_bytes());
+            payload.extend_from_slice(&chunk_addr.to_le_bytes());
+            let reply = self.push(AppMailbox::AppSystem, SystemCommand::Peek as u8, payload)?;
+            if reply.payload.len() != chunk_len {
+                return Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: SystemCommand::Peek as u8});
+            }
+            out.extend_from_slice(&reply.payload);
         }
+        Ok(out)
     }
 
-    pub fn has_bootloader(&self) -> Result<bool, libusb::Error> {
+    pub fn has_bootloader(&self) -> Result<bool, Error> {
         // SFR I2SCLKF0 & I2SCLKF1
         match self.peek(0xDF46, 2) {
             Ok(magic) => {
+                if magic.len() < 2 {
+                    return Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: SystemCommand::Peek as u8});
+                }
                 // 0x0DF0 ? I don't get it
                 return Ok(magic[0] == 0xF0 && magic[1] == 0x0D);
             },
@@ -405,226 +851,1083 @@ impl<'a> RFCatDevice<'a> {
 
     // [DRAGONS REDACTED]
 
-    pub fn poke(&self, addr: u16, data: u8) -> Result<(), libusb::Error> {
-        // self.push(AppMailbox::AppSystem, SystemCommand::Poke, vec![addr.to_le_bytes(), data]);
-        Ok(())
+    /* write bytes into XDATA starting at addr */
+    pub fn poke(&self, addr: u16, data: &[u8]) -> Result<(), Error> {
+        self.poke_cmd(SystemCommand::Poke as u8, addr, data)
+    }
+
+    /*
+     * like poke, but as POKE_REG, which the firmware writes a byte at a time.
+     * the 0xDF00 radio block doesn't need it: with the radio idle, plain poke
+     * is what rfcat's setFreq / setRadioConfig use, and so do set_frequency,
+     * set_radio_config and update_radio_config here (all inside with_idle).
+     */
+    pub fn poke_reg(&self, addr: u16, data: &[u8]) -> Result<(), Error> {
+        self.poke_cmd(SystemCommand::PokeRegister as u8, addr, data)
     }
 
-    pub fn poke_reg(&self, addr: u16, data: u8) -> Result<(), libusb::Error> {
-        // self.push(AppMailbox::AppSystem, SystemCommand::PokeRegister, vec!([addr.to_le_bytes(), data]));
+    /* poke, then peek it back and make sure it stuck (don't use on strobes or read-only registers) */
+    pub fn poke_verified(&self, addr: u16, data: &[u8]) -> Result<(), Error> {
+        self.poke(addr, data)?;
+        let readback = self.peek(addr, data.len() as u16)?;
+        if readback != data {
+            return Err(Error::VerifyFailed{addr: addr});
+        }
         Ok(())
-    }     
+    }
 
-    pub fn get_interrupt_registers(&self) -> Result<(), libusb::Error> {
+    /* poke_reg, then peek it back and make sure it stuck */
+    pub fn poke_reg_verified(&self, addr: u16, data: &[u8]) -> Result<(), Error> {
+        self.poke_reg(addr, data)?;
+        let readback = self.peek(addr, data.len() as u16)?;
+        if readback != data {
+            return Err(Error::VerifyFailed{addr: addr});
+        }
         Ok(())
     }
 
+    fn poke_cmd(&self, cmd: u8, addr: u16, data: &[u8]) -> Result<(), Error> {
+        if addr as usize + data.len() > 0x10000 {
+            return Err(Error::InvalidArgument(format!("poke of {} bytes at 0x{:04x} runs off the end of XDATA", data.len(), addr)));
+        }
+        for (i, chunk) in data.chunks(XDATA_CHUNK_LEN).enumerate() {
+            let chunk_addr = addr + (i * XDATA_CHUNK_LEN) as u16;
+            // payload: <addr le16> <data...>, CC answers with how many bytes it wrote (le16)
+            let mut payload = Vec::<u8>::with_capacity(chunk.len() + 2);
+            payload.extend_from_slice(&chunk_addr.to_le_bytes());
+            payload.extend_from_slice(chunk);
+            let reply = self.push(AppMailbox::AppSystem, cmd, payload)?;
+            if reply.payload.len() < 2 {
+                return Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: cmd});
+            }
+            let written = u16::from_le_bytes([reply.payload[0], reply.payload[1]]);
+            if written as usize != chunk.len() {
+                return Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: cmd});
+            }
+        }
+        Ok(())
+    }
 
-    pub fn send(&self, mbx: AppMailbox, cmd: SystemCommand) -> Result<(), libusb::Error> {
+    pub fn get_interrupt_registers(&self) -> Result<(), Error> {
         Ok(())
     }
 
-    pub fn push(&self, mbx: AppMailbox, cmd: SystemCommand, payload: Vec<u8>) -> Result<(), libusb::Error> {
+
+    /* mail a bare command and wait for the CC's answer */
+    pub fn send(&self, mbx: AppMailbox, cmd: u8) -> Result<RfCatPacket, Error> {
+        self.push(mbx, cmd, Vec::<u8>::new())
+    }
+
+    /* mail a command with a payload and wait for the CC's answer */
+    pub fn push(&self, mbx: AppMailbox, cmd: u8, payload: Vec<u8>) -> Result<RfCatPacket, Error> {
+        self.push_timeout(mbx, cmd, payload, self.timeout())
+    }
+
+    /* push, for commands the CC takes longer than usual to answer */
+    pub fn push_timeout(&self, mbx: AppMailbox, cmd: u8, payload: Vec<u8>, timeout: Duration) -> Result<RfCatPacket, Error> {
+        self.mail(RfCatPacket::payload(mbx, cmd, payload))?;
+        self.recv_mailbox(mbx, cmd, timeout)
+    }
+
+
+    pub fn set_rf_mode(&self, rfmode: RfState) -> Result<(), Error> {
+        self.push(AppMailbox::AppSystem, SystemCommand::RFMode as u8, vec![rfmode as u8])?;
+        self.radio_mode.set(Some(rfmode));
         Ok(())
     }
 
+    /* the mode the radio returns to, if we know it (set through set_mode_*, or seen by a register change) */
+    pub fn radio_mode(&self) -> Option<RfState> {
+        self.radio_mode.get()
+    }
+
+    /* where the radio's state machine is at right now */
+    pub fn get_marc_state(&self) -> Result<MainRadioControlState, Error> {
+        let marcstate = self.peek(CCRegisters::MarcState as u16, 1)?;
+        match marcstate.first() {
+            Some(state) => Ok(MainRadioControlState::from(*state)),
+            None => Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: SystemCommand::Peek as u8}),
+        }
+    }
 
-    pub fn set_rf_mode(&self, rfmode: RfState) {
-        // self.currentRfMode = rfmode;
-        self.push(AppMailbox::AppSystem, SystemCommand::RFMode, vec![rfmode as u8]);
+    /* poll MARCSTATE until the radio gets to state; Error::Timeout if it doesn't in time */
+    pub fn wait_for_state(&self, state: MainRadioControlState, timeout: Duration) -> Result<(), Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.get_marc_state()? == state {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+            thread::sleep(MARCSTATE_POLL_INTERVAL);
+        }
     }
 
     //     ### set standard radio state to TX/RX/IDLE (TX is pretty much only good for jamming).  TX/RX modes are set to return to whatever state you choose here.
-    pub fn set_mode_tx(&self) {
+    pub fn set_mode_tx(&self) -> Result<(), Error> {
         //         BOTH: set radio to TX state
         //         AND:  set radio to return to TX state when done with other states
-        self.set_rf_mode(RfState::STX);
+        self.set_rf_mode(RfState::STX)
     }
             
-    pub fn set_mode_rx(&self) {
+    pub fn set_mode_rx(&self) -> Result<(), Error> {
         //         BOTH: set radio to RX state
         //         AND:  set radio to return to RX state when done with other states
-        self.set_rf_mode(RfState::SRX);
+        self.set_rf_mode(RfState::SRX)
     }
 
-    pub fn set_mode_idle(&self) {
+    pub fn set_mode_idle(&self) -> Result<(), Error> {
         //         BOTH: set radio to IDLE state
         //         AND:  set radio to return to IDLE state when done with other states
-        self.set_rf_mode(RfState::SIDLE);
+        self.set_rf_mode(RfState::SIDLE)
     }
 
-    pub fn strobe_rf_mode(&self, rfmode: RfState) {
-        self.poke(Addresses::RfState as u16, rfmode as u8);
+    pub fn strobe_rf_mode(&self, rfmode: RfState) -> Result<(), Error> {
+        self.poke(Addresses::RfState as u16, &[rfmode as u8])
     }
 
     //     ### send raw state change to radio (doesn't update the return state for after RX/TX occurs)
-    pub fn strobe_mode_tx(&self) {
+    pub fn strobe_mode_tx(&self) -> Result<(), Error> {
         //         set radio to TX state (transient)
-        self.strobe_rf_mode(RfState::STX);
+        self.strobe_rf_mode(RfState::STX)
     }
 
-    pub fn strobe_mode_rx(&self) {
+    pub fn strobe_mode_rx(&self) -> Result<(), Error> {
         //         set radio to RX state (transient)
-        self.strobe_rf_mode(RfState::SRX);
+        self.strobe_rf_mode(RfState::SRX)
     }
 
-    pub fn strobe_mode_idle(&self) {
+    pub fn strobe_mode_idle(&self) -> Result<(), Error> {
         //         set radio to IDLE state (transient)
-        self.strobe_rf_mode(RfState::SIDLE);
+        self.strobe_rf_mode(RfState::SIDLE)
     }
 
-    pub fn strobe_mode_fstxon(&self) {
+    pub fn strobe_mode_fstxon(&self) -> Result<(), Error> {
         //         set radio to FSTXON state (transient)
-        self.strobe_rf_mode(RfState::SFSTXON);
+        self.strobe_rf_mode(RfState::SFSTXON)
     }
 
-    pub fn strobe_mode_cal(&self) {
+    pub fn strobe_mode_cal(&self) -> Result<(), Error> {
         //         set radio to CAL state (will return to whichever state is configured (via setMode* pub fntions)
-        self.strobe_rf_mode(RfState::SCAL);
+        self.strobe_rf_mode(RfState::SCAL)
     }
 
-    pub fn strobe_mode_return(&self) {
-    //         attempts to return the the correct mode after configuring some radio register(s).
-    //         it uses the marcstate provided (or self.radiocfg.marcstate if none are provided) to determine how to strobe the radio.
-    //         #if marcstate is None:
-    //             #marcstate = self.radiocfg.marcstate
-    //         #if self._debug: print("MARCSTATE: %x   returning to %x" % (marcstate, MARC_STATE_MAPPINGS[marcstate][2]) )
-    //         #self.poke(X_RFST, "%c"%MARC_STATE_MAPPINGS[marcstate][2])
-    //         self.poke(X_RFST, "%c" % self._rfmode)
+    /*
+     * put the radio back into the mode it's meant to be in (TX/RX/IDLE, as
+     * tracked in radio_mode) after configuring some radio register(s), and
+     * wait for it to get there.  with no mode known, it's left idle.
+     */
+    pub fn strobe_mode_return(&self) -> Result<(), Error> {
+        let mode = self.radio_mode.get().unwrap_or(RfState::SIDLE);
+        self.strobe_rf_mode(mode)?;
+        match MainRadioControlState::entered_by(mode) {
+            Some(state) => self.wait_for_state(state, STATE_CHANGE_TIMEOUT),
+            None => Ok(()),
+        }
     }
 
-    pub fn get_frequency(&self) -> u64 {
-        let freq: u64 = 0;
-
-        freq
+    /* the registers that move the synthesizer: changing any of them means a recalibration */
+    fn synth_registers(&self) -> Result<Vec<u8>, Error> {
+        let start = CCRegisters::ChanNr as u16;
+        self.peek(start, CCRegisters::MdmCfg0 as u16 - start + 1)
     }
-        
 
-    pub fn get_radio_config(&self) -> Result<RadioConfig, libusb::Error> {
-        match self.peek(0xdf00, 0x3e) {
-            Ok(data) => Ok(RadioConfig{}),
+    /*
+     * run f with the radio idled, as the CC1111 wants it for most register
+     * writes: strobe SIDLE and wait for MARCSTATE to say IDLE, run f,
+     * recalibrate if f moved the frequency, then put the radio back into the
+     * mode it was in (tracked or read from MARCSTATE), whether f worked or not.
+     * nested calls just run f; the outermost one does the idling.
+     */
+    pub fn with_idle<F, T>(&self, f: F) -> Result<T, Error>
+        where F: FnOnce(&Self) -> Result<T, Error> {
+        if self.idling.get() {
+            return f(self);
+        }
+        if self.radio_mode.get().is_none() {
+            let mode = self.get_marc_state()?.return_strobe();
+            self.radio_mode.set(Some(mode));
+        }
+        self.strobe_mode_idle()?;
+        if let Err(err) = self.wait_for_state(MainRadioControlState::Idle, STATE_CHANGE_TIMEOUT) {
+            // best effort: the radio not idling is what the caller needs to hear about
+            let _ = self.strobe_mode_return();
+            return Err(err);
+        }
+        self.idling.set(true);
+        let result = match self.synth_registers() {
+            Ok(before) => f(self).and_then(|out| {
+                if self.synth_registers()? != before {
+                    self.strobe_mode_cal()?;
+                    self.wait_for_state(MainRadioControlState::Idle, STATE_CHANGE_TIMEOUT)?;
+                }
+                Ok(out)
+            }),
             Err(err) => Err(err),
+        };
+        self.idling.set(false);
+        match (result, self.strobe_mode_return()) {
+            (Ok(out), Ok(())) => Ok(out),
+            // f's error is the interesting one
+            (Err(err), _) => Err(err),
+            (Ok(_), Err(err)) => Err(err),
         }
     }
 
-    pub fn make_from_libusb(
-        device: libusb::Device,
-        device_desc: libusb::DeviceDescriptor,
-    ) -> Result<RFCatDevice, libusb::Error> {
-        let mut handle = match device.open() {
-            Ok(k) => k,
-            Err(err) => {
-                println!("Error opening device: {}", err);
-                return Err(err);
-            }
-        };
-        let bus_number = device.bus_number();
-        let address = device.address();
-        let vendor_id = device_desc.vendor_id();
-        let product_id = device_desc.product_id();
-        let timeout = Duration::from_secs(1);
-        let langs = match handle.read_languages(timeout) {
-            Ok(k) => k,
-            Err(err) => {
-                println!("Error in reading languages: {}", err);
-                return Err(err);
-            }
-        };
-        let language: Option<libusb::Language>;
-        if langs.len() > 0 {
-            language = Some(langs[0]);
-        } else {
-            language = None;
+    pub fn get_frequency(&self) -> Result<u64, Error> {
+        let freq = self.peek(CCRegisters::Freq2 as u16, 3)?;
+        if freq.len() != 3 {
+            return Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: SystemCommand::Peek as u8});
         }
-        let mut in_max_size: u16 = 64;
-        let mut in_ep_addr: u8 = 0;
-        let mut out_ep_addr: u8 = 0;
-        match handle.reset() {
-            Ok(k) => k,
-            Err(err) => {
-                println!("Error resetting device: {}", err);
-                return Err(err);
-            }
-        };
-        for n in 0..device_desc.num_configurations() {
-            let config_desc = match device.config_descriptor(n) {
-                Ok(k) => k,
-                Err(err) => {
-                    println!("Error getting config descriptor: {}", err);
-                    return Err(err);
-                }
-            };
-            for interface in config_desc.interfaces() {
-                for interface_desc in interface.descriptors() {
-                    for endpoint_desc in interface_desc.endpoint_descriptors() {
-                        if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
-                                endpoint_desc.direction() == libusb::Direction::In {
-                            in_ep_addr = endpoint_desc.address();
-                            in_max_size = endpoint_desc.max_packet_size();
-                        }
-                        if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
-                                endpoint_desc.direction() == libusb::Direction::Out {
-                            out_ep_addr = endpoint_desc.address();
-                        }
+        Ok(freq_regs_to_hz(freq[0], freq[1], freq[2]))
+    }
 
-                    }
-                }
+    /* tune to hz (as close as the synthesizer gets), pick the VCO and recalibrate; returns the actual frequency */
+    pub fn set_frequency(&self, hz: u64) -> Result<u64, Error> {
+        let mut config = RadioConfig::default();
+        let actual = config.set_frequency(hz)?;
+        self.with_idle(|dev| {
+            dev.poke(CCRegisters::Freq2 as u16, &[config.freq2, config.freq1, config.freq0])?;
+            dev.poke(CCRegisters::FsCal2 as u16, &[config.fscal2])?;
+            if let Some(mut known) = dev.radio_config.get() {
+                known.freq2 = config.freq2;
+                known.freq1 = config.freq1;
+                known.freq0 = config.freq0;
+                known.fscal2 = config.fscal2;
+                dev.radio_config.set(Some(known));
             }
+            Ok(())
+        })?;
+        Ok(actual)
+    }
+
+    pub fn get_radio_config(&self) -> Result<RadioConfig, Error> {
+        match self.peek(CCRegisters::Sync1 as u16, RADIO_CONFIG_LEN) {
+            Ok(data) => {
+                let config = RadioConfig::from_bytes(&data[..])?;
+                self.append_status.set(Some(config.append_status()));
+                self.radio_config.set(Some(config));
+                Ok(config)
+            },
+            Err(err) => Err(err),
         }
-        match handle.set_active_configuration(1) {
-            Ok(k) => k,
-            Err(err) => {
-                println!("Error setting configuration: {}", err);
-                return Err(err);
+    }
+
+    /* write a whole config back, with the radio idled while the registers change */
+    pub fn set_radio_config(&self, config: &RadioConfig) -> Result<(), Error> {
+        self.with_idle(|dev| {
+            dev.poke(CCRegisters::Sync1 as u16, &config.writable_bytes()[..])?;
+            dev.append_status.set(Some(config.append_status()));
+            dev.radio_config.set(Some(*config));
+            Ok(())
+        })
+    }
+
+    /* the radio config as last read or written through this device, without asking the CC */
+    pub fn last_radio_config(&self) -> Option<RadioConfig> {
+        self.radio_config.get()
+    }
+
+    /*
+     * read the radio config, let f change it, then write back only the registers
+     * that actually changed, with the radio idled while they do
+     */
+    pub fn update_radio_config<F, T>(&self, f: F) -> Result<T, Error>
+        where F: FnOnce(&mut RadioConfig) -> Result<T, Error> {
+        let old = self.get_radio_config()?;
+        let mut new = old;
+        let out = f(&mut new)?;
+        let old_bytes = old.writable_bytes();
+        let new_bytes = new.writable_bytes();
+        if old_bytes == new_bytes {
+            return Ok(out);
+        }
+        self.with_idle(|dev| {
+            let mut offset = 0;
+            while offset < new_bytes.len() {
+                if old_bytes[offset] == new_bytes[offset] {
+                    offset += 1;
+                    continue;
+                }
+                let start = offset;
+                while offset < new_bytes.len() && old_bytes[offset] != new_bytes[offset] {
+                    offset += 1;
+                }
+                dev.poke(CCRegisters::Sync1 as u16 + start as u16, &new_bytes[start..offset])?;
             }
+            dev.append_status.set(Some(new.append_status()));
+            dev.radio_config.set(Some(new));
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    pub fn get_modulation(&self) -> Result<ModulationFormat, Error> {
+        Ok(self.get_radio_config()?.modulation())
+    }
+
+    pub fn set_modulation(&self, modulation: ModulationFormat) -> Result<(), Error> {
+        self.update_radio_config(|cfg| cfg.set_modulation(modulation))
+    }
+
+    pub fn get_data_rate(&self) -> Result<f64, Error> {
+        Ok(self.get_radio_config()?.data_rate())
+    }
+
+    pub fn set_data_rate(&self, baud: f64) -> Result<f64, Error> {
+        self.update_radio_config(|cfg| cfg.set_data_rate(baud))
+    }
+
+    pub fn get_deviation(&self) -> Result<f64, Error> {
+        Ok(self.get_radio_config()?.deviation())
+    }
+
+    pub fn set_deviation(&self, hz: f64) -> Result<f64, Error> {
+        self.update_radio_config(|cfg| cfg.set_deviation(hz))
+    }
+
+    pub fn get_channel_bandwidth(&self) -> Result<f64, Error> {
+        Ok(self.get_radio_config()?.channel_bandwidth())
+    }
+
+    pub fn set_channel_bandwidth(&self, hz: f64) -> Result<f64, Error> {
+        self.update_radio_config(|cfg| cfg.set_channel_bandwidth(hz))
+    }
+
+    pub fn get_sync_word(&self) -> Result<u16, Error> {
+        Ok(self.get_radio_config()?.sync_word())
+    }
+
+    pub fn set_sync_word(&self, word: u16) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_sync_word(word);
+            Ok(())
+        })
+    }
+
+    pub fn get_sync_mode(&self) -> Result<SyncM, Error> {
+        Ok(self.get_radio_config()?.sync_mode())
+    }
+
+    pub fn set_sync_mode(&self, mode: SyncM) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_sync_mode(mode);
+            Ok(())
+        })
+    }
+
+    pub fn get_packet_length(&self) -> Result<PacketLength, Error> {
+        Ok(self.get_radio_config()?.packet_length())
+    }
+
+    pub fn set_packet_length(&self, length: PacketLength) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_packet_length(length);
+            Ok(())
+        })
+    }
+
+    pub fn get_crc(&self) -> Result<bool, Error> {
+        Ok(self.get_radio_config()?.crc())
+    }
+
+    pub fn set_crc(&self, enable: bool) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_crc(enable);
+            Ok(())
+        })
+    }
+
+    pub fn get_whitening(&self) -> Result<bool, Error> {
+        Ok(self.get_radio_config()?.whitening())
+    }
+
+    pub fn set_whitening(&self, enable: bool) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_whitening(enable);
+            Ok(())
+        })
+    }
+
+    pub fn get_preamble_length(&self) -> Result<u8, Error> {
+        Ok(self.get_radio_config()?.preamble_length())
+    }
+
+    pub fn set_preamble_length(&self, bytes: u8) -> Result<u8, Error> {
+        self.update_radio_config(|cfg| cfg.set_preamble_length(bytes))
+    }
+
+    pub fn get_address_filter(&self) -> Result<(AddressCheck, u8), Error> {
+        Ok(self.get_radio_config()?.address_filter())
+    }
+
+    pub fn set_address_filter(&self, check: AddressCheck, addr: u8) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_address_filter(check, addr);
+            Ok(())
+        })
+    }
+
+    pub fn get_append_status(&self) -> Result<bool, Error> {
+        Ok(self.get_radio_config()?.append_status())
+    }
+
+    pub fn set_append_status(&self, enable: bool) -> Result<(), Error> {
+        self.update_radio_config(|cfg| {
+            cfg.set_append_status(enable);
+            Ok(())
+        })
+    }
+
+    /* NIC layer */
+
+    /* wait up to timeout for the next packet the radio hands up (radio must be in RX) */
+    pub fn recv_rf(&self, timeout: Duration) -> Result<RfFrame, Error> {
+        let append_status = self.append_status()?;
+        let pkt = self.recv_mailbox(AppMailbox::AppNic, NicCommand::Recv as u8, timeout)?;
+        self.rf_frame(pkt.payload, append_status)
+    }
+
+    /* iterate over received RF frames, stopping once none arrives within timeout */
+    pub fn rf_frames<'d>(&'d self, timeout: Duration) -> RfFrames<'d, 'a> {
+        RfFrames{device: self, timeout: timeout, done: false}
+    }
+
+    fn append_status(&self) -> Result<bool, Error> {
+        match self.append_status.get() {
+            Some(append_status) => Ok(append_status),
+            None => {
+                let pktctrl1 = self.peek(CCRegisters::PktCtrl1 as u16, 1)?;
+                let append_status = pktctrl1.first().is_some_and(|v| registers::PKTCTRL1_APPEND_STATUS.is_set(*v));
+                self.append_status.set(Some(append_status));
+                Ok(append_status)
+            },
         }
-        match handle.claim_interface(0) {
-            Ok(k) => k,
-            Err(err) => {
-                println!("Error claiming interface: {}", err);
-                return Err(err);
+    }
+
+    fn rf_frame(&self, mut payload: Vec<u8>, append_status: bool) -> Result<RfFrame, Error> {
+        let (rssi, lqi_crc) = if append_status && payload.len() >= 2 {
+            let status = payload.split_off(payload.len() - 2);
+            (status[0], status[1])
+        } else {
+            // no status bytes on the packet, go ask the radio
+            let status = self.peek(CCRegisters::Lqi as u16, 2)?;
+            if status.len() != 2 {
+                return Err(Error::UnexpectedReply{mbx: AppMailbox::AppSystem, cmd: SystemCommand::Peek as u8});
             }
+            (status[1], status[0])
+        };
+        Ok(RfFrame{
+            payload: payload,
+            rssi: rssi,
+            lqi: registers::LQI_LQI_EST.get(lqi_crc),
+            crc_ok: registers::LQI_CRC_OK.is_set(lqi_crc),
+        })
+    }
+
+    /*
+     * send data over the air (NIC_XMIT).  repeat sends it that many more times,
+     * each repeat starting at offset into data.  data must fit the firmware's
+     * transmit block; see transmit_long for anything bigger.
+     */
+    pub fn transmit(&self, data: &[u8], repeat: u16, offset: u16) -> Result<(), Error> {
+        if data.is_empty() || data.len() > RF_MAX_TX_BLOCK || offset as usize >= data.len() {
+            return Err(Error::InvalidArgument(format!("transmit of {} bytes at offset {}", data.len(), offset)));
         }
-        match handle.set_alternate_setting(0, 0) {
-            Ok(k) => k,
-            Err(err) => {
-                println!("Error alternate setting: {}", err);
-                return Err(err);
+        let mut payload = Vec::<u8>::with_capacity(data.len() + 6);
+        payload.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        payload.extend_from_slice(&repeat.to_le_bytes());
+        payload.extend_from_slice(&offset.to_le_bytes());
+        payload.extend_from_slice(data);
+        // the CC only answers once it's done on the air, so give it time per block
+        let waitlen = data.len() + repeat as usize * (data.len() - offset as usize);
+        let wait = self.timeout() * (waitlen / RF_MAX_TX_BLOCK + 1) as u32;
+        let reply = self.push_timeout(AppMailbox::AppNic, NicCommand::Xmit as u8, payload, wait)?;
+        self.nic_return_code(&reply)?.into_result()
+    }
+
+    /*
+     * send a payload bigger than the firmware's transmit block (NIC_LONG_XMIT):
+     * preload what fits, then feed it the rest chunk by chunk as buffers free up
+     */
+    pub fn transmit_long(&self, data: &[u8]) -> Result<(), Error> {
+        if data.is_empty() || data.len() > RF_MAX_TX_LONG {
+            return Err(Error::InvalidArgument(format!("long transmit of {} bytes", data.len())));
+        }
+        let chunks: Vec<&[u8]> = data.chunks(RF_MAX_TX_CHUNK).collect();
+        let preload = std::cmp::min(RF_MAX_TX_BLOCK / RF_MAX_TX_CHUNK, chunks.len());
+
+        let mut payload = Vec::<u8>::with_capacity(RF_MAX_TX_BLOCK + 3);
+        payload.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        payload.push(preload as u8);
+        for chunk in chunks[..preload].iter() {
+            payload.extend_from_slice(chunk);
+        }
+        let reply = self.push(AppMailbox::AppNic, NicCommand::LongXmit as u8, payload)?;
+        self.nic_return_code(&reply)?.into_result()?;
+
+        for chunk in chunks[preload..].iter() {
+            self.transmit_long_more(chunk)?;
+        }
+        // an empty chunk tells the CC we're done
+        self.transmit_long_more(&[])
+    }
+
+    fn transmit_long_more(&self, chunk: &[u8]) -> Result<(), Error> {
+        let deadline = Instant::now() + self.timeout();
+        loop {
+            let mut payload = Vec::<u8>::with_capacity(chunk.len() + 1);
+            payload.push(chunk.len() as u8);
+            payload.extend_from_slice(chunk);
+            let reply = self.push(AppMailbox::AppNic, NicCommand::LongXmitMore as u8, payload)?;
+            match self.nic_return_code(&reply)? {
+                // flow control: the CC is still busy with earlier chunks
+                NicReturnCode::TempErrBufferNotAvailable if Instant::now() < deadline => {
+                    thread::sleep(TX_BUFFER_BACKOFF);
+                },
+                code => return code.into_result(),
             }
         }
-        Ok(RFCatDevice{
-            bus_number: bus_number,
-            address: address,
-            vendor_id: vendor_id,
-            product_id: product_id,
-            handle: handle,
-            descriptor: device_desc,
-            language: language,
-            timeout: timeout,
-            max_input_size: in_max_size,
-            in_endpoint_address: in_ep_addr,
-            out_endpoint_address: out_ep_addr,
-            radio_mode: None,
-            mailbox_queues: HashMap::new(),
-        })
+    }
+
+    /* the status byte NIC transmit replies carry; no byte is no answer */
+    fn nic_return_code(&self, reply: &RfCatPacket) -> Result<NicReturnCode, Error> {
+        match reply.payload.first() {
+            Some(code) => Ok(NicReturnCode::from(*code)),
+            None => Err(Error::UnexpectedReply{mbx: reply.mbx, cmd: reply.cmd}),
+        }
+    }
+
+    /* bigger RX blocks for long packets (0 goes back to normal) */
+    pub fn set_recv_large(&self, blocksize: u16) -> Result<(), Error> {
+        self.push(AppMailbox::AppNic, NicCommand::SetRecvLarge as u8, blocksize.to_le_bytes().to_vec())?;
+        Ok(())
+    }
+
+    /* amplifier control on boards that have one (YS1) */
+    pub fn set_amp_mode(&self, ampmode: u8) -> Result<(), Error> {
+        self.push(AppMailbox::AppNic, NicCommand::SetAmpMode as u8, vec![ampmode])?;
+        Ok(())
+    }
+
+    pub fn get_amp_mode(&self) -> Result<u8, Error> {
+        let reply = self.send(AppMailbox::AppNic, NicCommand::GetAmpMode as u8)?;
+        match reply.payload.first() {
+            Some(ampmode) => Ok(*ampmode),
+            None => Err(Error::UnexpectedReply{mbx: AppMailbox::AppNic, cmd: NicCommand::GetAmpMode as u8}),
+        }
+    }
+
+    pub fn set_aes_mode(&self, aesmode: u8) -> Result<(), Error> {
+        self.push(AppMailbox::AppNic, NicCommand::SetAesMode as u8, vec![aesmode])?;
+        Ok(())
+    }
+
+    pub fn get_aes_mode(&self) -> Result<u8, Error> {
+        let reply = self.send(AppMailbox::AppNic, NicCommand::GetAesMode as u8)?;
+        match reply.payload.first() {
+            Some(aesmode) => Ok(*aesmode),
+            None => Err(Error::UnexpectedReply{mbx: AppMailbox::AppNic, cmd: NicCommand::GetAesMode as u8}),
+        }
+    }
+
+    pub fn set_aes_iv(&self, iv: &[u8; 16]) -> Result<(), Error> {
+        self.push(AppMailbox::AppNic, NicCommand::SetAesIv as u8, iv.to_vec())?;
+        Ok(())
+    }
+
+    pub fn set_aes_key(&self, key: &[u8; 16]) -> Result<(), Error> {
+        self.push(AppMailbox::AppNic, NicCommand::SetAesKey as u8, key.to_vec())?;
+        Ok(())
+    }
+
+    pub fn make_from_libusb(
+        device: libusb::Device<'a>,
+        device_desc: libusb::DeviceDescriptor,
+    ) -> Result<RFCatDevice<'a>, Error> {
+        match UsbTransport::open(device, device_desc) {
+            Ok(transport) => Ok(RFCatDevice::from_transport(Box::new(transport))),
+            Err(err) => Err(err),
+        }
     }
 }
 
+/* the CC's radio register block, 0xDF00 - 0xDF3D, one field per register */
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct RadioConfig {
-
+    pub sync1: u8,
+    pub sync0: u8,
+    pub pktlen: u8,
+    pub pktctrl1: u8,
+    pub pktctrl0: u8,
+    pub addr: u8,
+    pub channr: u8,
+    pub fsctrl1: u8,
+    pub fsctrl0: u8,
+    pub freq2: u8,
+    pub freq1: u8,
+    pub freq0: u8,
+    pub mdmcfg4: u8,
+    pub mdmcfg3: u8,
+    pub mdmcfg2: u8,
+    pub mdmcfg1: u8,
+    pub mdmcfg0: u8,
+    pub deviatn: u8,
+    pub mcsm2: u8,
+    pub mcsm1: u8,
+    pub mcsm0: u8,
+    pub foccfg: u8,
+    pub bscfg: u8,
+    pub agcctrl2: u8,
+    pub agcctrl1: u8,
+    pub agcctrl0: u8,
+    pub frend1: u8,
+    pub frend0: u8,
+    pub fscal3: u8,
+    pub fscal2: u8,
+    pub fscal1: u8,
+    pub fscal0: u8,
+    // 0xDF20 - 0xDF22 reserved
+    pub z0: u8,
+    pub z1: u8,
+    pub z2: u8,
+    pub test2: u8,
+    pub test1: u8,
+    pub test0: u8,
+    // 0xDF26 reserved
+    pub z3: u8,
+    pub pa_table7: u8,
+    pub pa_table6: u8,
+    pub pa_table5: u8,
+    pub pa_table4: u8,
+    pub pa_table3: u8,
+    pub pa_table2: u8,
+    pub pa_table1: u8,
+    pub pa_table0: u8,
+    pub iocfg2: u8,
+    pub iocfg1: u8,
+    pub iocfg0: u8,
+    // 0xDF32 - 0xDF35 reserved
+    pub z4: u8,
+    pub z5: u8,
+    pub z6: u8,
+    pub z7: u8,
+    // read-only status registers
+    pub partnum: u8,
+    pub chipid: u8,
+    pub freqest: u8,
+    pub lqi: u8,
+    pub rssi: u8,
+    pub marcstate: u8,
+    pub pkstatus: u8,
+    pub vco_vc_dac: u8,
 }
 impl RadioConfig {
-    fn from_bytes(v: Vec<u8>) -> Result<RadioConfig, libusb::Error> {
-        // deserialize here
-        Ok(RadioConfig{})
+    pub fn from_bytes(v: &[u8]) -> Result<RadioConfig, Error> {
+        if v.len() < RADIO_CONFIG_LEN as usize {
+            return Err(Error::InvalidArgument(format!("radio config is {} bytes, need {}", v.len(), RADIO_CONFIG_LEN)));
+        }
+        Ok(RadioConfig{
+            sync1: v[0x00],
+            sync0: v[0x01],
+            pktlen: v[0x02],
+            pktctrl1: v[0x03],
+            pktctrl0: v[0x04],
+            addr: v[0x05],
+            channr: v[0x06],
+            fsctrl1: v[0x07],
+            fsctrl0: v[0x08],
+            freq2: v[0x09],
+            freq1: v[0x0a],
+            freq0: v[0x0b],
+            mdmcfg4: v[0x0c],
+            mdmcfg3: v[0x0d],
+            mdmcfg2: v[0x0e],
+            mdmcfg1: v[0x0f],
+            mdmcfg0: v[0x10],
+            deviatn: v[0x11],
+            mcsm2: v[0x12],
+            mcsm1: v[0x13],
+            mcsm0: v[0x14],
+            foccfg: v[0x15],
+            bscfg: v[0x16],
+            agcctrl2: v[0x17],
+            agcctrl1: v[0x18],
+            agcctrl0: v[0x19],
+            frend1: v[0x1a],
+            frend0: v[0x1b],
+            fscal3: v[0x1c],
+            fscal2: v[0x1d],
+            fscal1: v[0x1e],
+            fscal0: v[0x1f],
+            z0: v[0x20],
+            z1: v[0x21],
+            z2: v[0x22],
+            test2: v[0x23],
+            test1: v[0x24],
+            test0: v[0x25],
+            z3: v[0x26],
+            pa_table7: v[0x27],
+            pa_table6: v[0x28],
+            pa_table5: v[0x29],
+            pa_table4: v[0x2a],
+            pa_table3: v[0x2b],
+            pa_table2: v[0x2c],
+            pa_table1: v[0x2d],
+            pa_table0: v[0x2e],
+            iocfg2: v[0x2f],
+            iocfg1: v[0x30],
+            iocfg0: v[0x31],
+            z4: v[0x32],
+            z5: v[0x33],
+            z6: v[0x34],
+            z7: v[0x35],
+            partnum: v[0x36],
+            chipid: v[0x37],
+            freqest: v[0x38],
+            lqi: v[0x39],
+            rssi: v[0x3a],
+            marcstate: v[0x3b],
+            pkstatus: v[0x3c],
+            vco_vc_dac: v[0x3d],
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        vec![
+            self.sync1,
+            self.sync0,
+            self.pktlen,
+            self.pktctrl1,
+            self.pktctrl0,
+            self.addr,
+            self.channr,
+            self.fsctrl1,
+            self.fsctrl0,
+            self.freq2,
+            self.freq1,
+            self.freq0,
+            self.mdmcfg4,
+            self.mdmcfg3,
+            self.mdmcfg2,
+            self.mdmcfg1,
+            self.mdmcfg0,
+            self.deviatn,
+            self.mcsm2,
+            self.mcsm1,
+            self.mcsm0,
+            self.foccfg,
+            self.bscfg,
+            self.agcctrl2,
+            self.agcctrl1,
+            self.agcctrl0,
+            self.frend1,
+            self.frend0,
+            self.fscal3,
+            self.fscal2,
+            self.fscal1,
+            self.fscal0,
+            self.z0,
+            self.z1,
+            self.z2,
+            self.test2,
+            self.test1,
+            self.test0,
+            self.z3,
+            self.pa_table7,
+            self.pa_table6,
+            self.pa_table5,
+            self.pa_table4,
+            self.pa_table3,
+            self.pa_table2,
+            self.pa_table1,
+            self.pa_table0,
+            self.iocfg2,
+            self.iocfg1,
+            self.iocfg0,
+            self.z4,
+            self.z5,
+            self.z6,
+            self.z7,
+            self.partnum,
+            self.chipid,
+            self.freqest,
+            self.lqi,
+            self.rssi,
+            self.marcstate,
+            self.pkstatus,
+            self.vco_vc_dac,
+        ]
+    }
+
+    pub fn frequency(&self) -> u64 {
+        freq_regs_to_hz(self.freq2, self.freq1, self.freq0)
+    }
+
+    /* FREQ2/1/0 plus the FSCAL2 VCO pick, same as rfcat's setFreq; returns the actual frequency */
+    pub fn set_frequency(&mut self, hz: u64) -> Result<u64, Error> {
+        let mhz = hz / 1_000_000;
+        if !BAND_LIMITS_MHZ.iter().any(|&(lo, hi)| mhz >= lo && mhz < hi) {
+            return Err(Error::InvalidArgument(format!("{} Hz is outside the CC1111's bands", hz)));
+        }
+        let num = (hz * 0x10000 + OSCILLATOR_HZ / 2) / OSCILLATOR_HZ;
+        self.freq2 = (num >> 16) as u8;
+        self.freq1 = (num >> 8) as u8;
+        self.freq0 = num as u8;
+
+        let mhz_of = |t: u64| t * 1_000_000;
+        if (hz > mhz_of(BandTransitionsMHz::BT900 as u64) && hz < mhz_of(VCOTransitionsMHz::VT900 as u64)) ||
+                (hz > mhz_of(BandTransitionsMHz::BT400 as u64) && hz < mhz_of(VCOTransitionsMHz::VT400 as u64)) ||
+                hz < mhz_of(VCOTransitionsMHz::VT300 as u64) {
+            self.fscal2 = FSCAL2_LOW_VCO;
+        } else {
+            self.fscal2 = FSCAL2_HIGH_VCO;
+        }
+        Ok(self.frequency())
+    }
+
+    pub fn modulation(&self) -> ModulationFormat {
+        ModulationFormat::from(self.mdmcfg2)
+    }
+
+    /* MDMCFG2 MOD_FORMAT; ASK/OOK also flips the PA table so PA_TABLE0 is "off" and PA_TABLE1 is "on" */
+    pub fn set_modulation(&mut self, modulation: ModulationFormat) -> Result<(), Error> {
+        if modulation == ModulationFormat::UNKNOWN {
+            return Err(Error::InvalidArgument("unknown modulation".to_string()));
+        }
+        self.mdmcfg2 = (self.mdmcfg2 & !registers::MDMCFG2_MOD_FORMAT.mask) | modulation as u8;
+        if modulation == ModulationFormat::AskOok {
+            if self.pa_table1 == 0x00 && self.pa_table0 != 0x00 {
+                self.pa_table1 = self.pa_table0;
+                self.pa_table0 = 0x00;
+            }
+            self.frend0 = registers::FREND0_PA_POWER.set(self.frend0, 1);
+        } else {
+            if self.pa_table0 == 0x00 && self.pa_table1 != 0x00 {
+                self.pa_table0 = self.pa_table1;
+                self.pa_table1 = 0x00;
+            }
+            self.frend0 = registers::FREND0_PA_POWER.set(self.frend0, 0);
+        }
+        Ok(())
+    }
+
+    /* baud, from MDMCFG4 DRATE_E and MDMCFG3 DRATE_M */
+    pub fn data_rate(&self) -> f64 {
+        let drate_e = registers::MDMCFG4_DRATE_E.get(self.mdmcfg4) as i32;
+        let drate_m = self.mdmcfg3 as f64;
+        OSCILLATOR_HZ as f64 * (256.0 + drate_m) * 2f64.powi(drate_e) / 2f64.powi(28)
+    }
+
+    /* closest achievable data rate to baud; returns the actual data rate */
+    pub fn set_data_rate(&mut self, baud: f64) -> Result<f64, Error> {
+        let mut best: Option<(u8, u8, f64)> = None;
+        for drate_e in 0..16u8 {
+            for drate_m in 0..=255u8 {
+                let rate = OSCILLATOR_HZ as f64 * (256.0 + drate_m as f64) * 2f64.powi(drate_e as i32) / 2f64.powi(28);
+                let closer = match best {
+                    Some((_, _, b)) => (rate - baud).abs() < (b - baud).abs(),
+                    None => true,
+                };
+                if closer {
+                    best = Some((drate_e, drate_m, rate));
+                }
+            }
+        }
+        match best {
+            Some((drate_e, drate_m, rate)) if is_close(rate, baud) => {
+                self.mdmcfg4 = registers::MDMCFG4_DRATE_E.set(self.mdmcfg4, drate_e);
+                self.mdmcfg3 = drate_m;
+                Ok(rate)
+            },
+            _ => Err(Error::InvalidArgument(format!("data rate {} baud", baud))),
+        }
+    }
+
+    /* FSK deviation in Hz, from DEVIATN */
+    pub fn deviation(&self) -> f64 {
+        let dev_e = registers::DEVIATN_DEVIATION_E.get(self.deviatn) as i32;
+        let dev_m = registers::DEVIATN_DEVIATION_M.get(self.deviatn) as f64;
+        OSCILLATOR_HZ as f64 * (8.0 + dev_m) * 2f64.powi(dev_e) / 2f64.powi(17)
+    }
+
+    /* closest achievable FSK deviation to hz; returns the actual deviation */
+    pub fn set_deviation(&mut self, hz: f64) -> Result<f64, Error> {
+        let mut best: Option<(u8, u8, f64)> = None;
+        for dev_e in 0..8u8 {
+            for dev_m in 0..8u8 {
+                let dev = OSCILLATOR_HZ as f64 * (8.0 + dev_m as f64) * 2f64.powi(dev_e as i32) / 2f64.powi(17);
+                let closer = match best {
+                    Some((_, _, b)) => (dev - hz).abs() < (b - hz).abs(),
+                    None => true,
+                };
+                if closer {
+                    best = Some((dev_e, dev_m, dev));
+                }
+            }
+        }
+        match best {
+            Some((dev_e, dev_m, dev)) if is_close(dev, hz) => {
+                self.deviatn = registers::DEVIATN_DEVIATION_E.set(registers::DEVIATN_DEVIATION_M.set(0, dev_m), dev_e);
+                Ok(dev)
+            },
+            _ => Err(Error::InvalidArgument(format!("deviation {} Hz", hz))),
+        }
+    }
+
+    /* RX channel filter bandwidth in Hz, from MDMCFG4 CHANBW_E/CHANBW_M */
+    pub fn channel_bandwidth(&self) -> f64 {
+        let chanbw_e = registers::MDMCFG4_CHANBW_E.get(self.mdmcfg4) as i32;
+        let chanbw_m = registers::MDMCFG4_CHANBW_M.get(self.mdmcfg4) as f64;
+        OSCILLATOR_HZ as f64 / (8.0 * (4.0 + chanbw_m) * 2f64.powi(chanbw_e))
+    }
+
+    /*
+     * closest achievable channel bandwidth to hz; returns the actual bandwidth.
+     * like rfcat, FREND1 and TEST2/TEST1 follow along for the wide filters.
+     */
+    pub fn set_channel_bandwidth(&mut self, hz: f64) -> Result<f64, Error> {
+        let mut best: Option<(u8, u8, f64)> = None;
+        for chanbw_e in 0..4u8 {
+            for chanbw_m in 0..4u8 {
+                let bw = OSCILLATOR_HZ as f64 / (8.0 * (4.0 + chanbw_m as f64) * 2f64.powi(chanbw_e as i32));
+                let closer = match best {
+                    Some((_, _, b)) => (bw - hz).abs() < (b - hz).abs(),
+                    None => true,
+                };
+                if closer {
+                    best = Some((chanbw_e, chanbw_m, bw));
+                }
+            }
+        }
+        match best {
+            Some((chanbw_e, chanbw_m, bw)) if is_close(bw, hz) => {
+                self.mdmcfg4 = registers::MDMCFG4_CHANBW_E.set(registers::MDMCFG4_CHANBW_M.set(self.mdmcfg4, chanbw_m), chanbw_e);
+                self.frend1 = if bw > 102e3 { 0xb6 } else { 0x56 };
+                if bw > 325e3 {
+                    self.test2 = 0x88;
+                    self.test1 = 0x31;
+                } else {
+                    self.test2 = 0x81;
+                    self.test1 = 0x35;
+                }
+                Ok(bw)
+            },
+            _ => Err(Error::InvalidArgument(format!("channel bandwidth {} Hz", hz))),
+        }
+    }
+
+    pub fn sync_word(&self) -> u16 {
+        u16::from_be_bytes([self.sync1, self.sync0])
+    }
+
+    pub fn set_sync_word(&mut self, word: u16) {
+        let bytes = word.to_be_bytes();
+        self.sync1 = bytes[0];
+        self.sync0 = bytes[1];
+    }
+
+    pub fn sync_mode(&self) -> SyncM {
+        SyncM::from(self.mdmcfg2)
+    }
+
+    pub fn set_sync_mode(&mut self, mode: SyncM) {
+        self.mdmcfg2 = registers::MDMCFG2_SYNC_MODE.set(self.mdmcfg2, mode as u8);
+    }
+
+    pub fn packet_length(&self) -> PacketLength {
+        match registers::PKTCTRL0_LENGTH_CONFIG.get(self.pktctrl0) {
+            0 => PacketLength::Fixed(self.pktlen),
+            1 => PacketLength::Variable(self.pktlen),
+            _ => PacketLength::Infinite,
+        }
+    }
+
+    pub fn set_packet_length(&mut self, length: PacketLength) {
+        let config = match length {
+            PacketLength::Fixed(len) => {
+                self.pktlen = len;
+                0
+            },
+            PacketLength::Variable(max) => {
+                self.pktlen = max;
+                1
+            },
+            PacketLength::Infinite => 2,
+        };
+        self.pktctrl0 = registers::PKTCTRL0_LENGTH_CONFIG.set(self.pktctrl0, config);
+    }
+
+    pub fn crc(&self) -> bool {
+        registers::PKTCTRL0_CRC_EN.is_set(self.pktctrl0)
+    }
+
+    pub fn set_crc(&mut self, enable: bool) {
+        self.pktctrl0 = registers::PKTCTRL0_CRC_EN.set(self.pktctrl0, enable as u8);
+    }
+
+    pub fn whitening(&self) -> bool {
+        registers::PKTCTRL0_WHITE_DATA.is_set(self.pktctrl0)
     }
-    fn to_bytes(&self) -> Result<Vec<u8>, libusb::Error> {
-        Ok(vec![0])
+
+    pub fn set_whitening(&mut self, enable: bool) {
+        self.pktctrl0 = registers::PKTCTRL0_WHITE_DATA.set(self.pktctrl0, enable as u8);
+    }
+
+    /* preamble bytes sent before the sync word */
+    pub fn preamble_length(&self) -> u8 {
+        PREAMBLE_BYTES[registers::MDMCFG1_NUM_PREAMBLE.get(self.mdmcfg1) as usize]
+    }
+
+    /* at least bytes of preamble (2, 3, 4, 6, 8, 12, 16 or 24); returns the actual length */
+    pub fn set_preamble_length(&mut self, bytes: u8) -> Result<u8, Error> {
+        match PREAMBLE_BYTES.iter().position(|&n| n >= bytes) {
+            Some(idx) => {
+                self.mdmcfg1 = registers::MDMCFG1_NUM_PREAMBLE.set(self.mdmcfg1, idx as u8);
+                Ok(PREAMBLE_BYTES[idx])
+            },
+            None => Err(Error::InvalidArgument(format!("{} bytes of preamble", bytes))),
+        }
+    }
+
+    pub fn address_filter(&self) -> (AddressCheck, u8) {
+        (AddressCheck::from(self.pktctrl1), self.addr)
+    }
+
+    pub fn set_address_filter(&mut self, check: AddressCheck, addr: u8) {
+        self.pktctrl1 = registers::PKTCTRL1_ADR_CHK.set(self.pktctrl1, check as u8);
+        self.addr = addr;
+    }
+
+    /* RSSI and LQI/CRC_OK tacked onto the end of each received packet */
+    pub fn append_status(&self) -> bool {
+        registers::PKTCTRL1_APPEND_STATUS.is_set(self.pktctrl1)
+    }
+
+    pub fn set_append_status(&mut self, enable: bool) {
+        self.pktctrl1 = registers::PKTCTRL1_APPEND_STATUS.set(self.pktctrl1, enable as u8);
+    }
+
+    /* the part of the block that is worth writing back (everything up to IOCFG0) */
+    pub fn writable_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes();
+        bytes.truncate(RADIO_CONFIG_WRITABLE_LEN as usize);
+        bytes
     }
 }
-    
 
 
+/* "closest achievable" still has to be in the neighbourhood: within a factor of two */
+fn is_close(actual: f64, wanted: f64) -> bool {
+    wanted > 0.0 && actual / wanted < 2.0 && wanted / actual < 2.0
+}
+
+fn freq_regs_to_hz(freq2: u8, freq1: u8, freq0: u8) -> u64 {
+    let num = ((freq2 as u64) << 16) | ((freq1 as u64) << 8) | freq0 as u64;
+    (num * OSCILLATOR_HZ + 0x8000) / 0x10000
+}
+
 // TODO: this should be a static vector for vps
 fn is_standard_rfcat(usbdd: &libusb::DeviceDescriptor) -> bool {
     match (usbdd.vendor_id(), usbdd.product_id()) {
@@ -643,31 +1946,86 @@ fn is_standard_rfcat(usbdd: &libusb::DeviceDescriptor) -> bool {
     }
 }
 
-fn parse_two_hex(twohex: &str) -> (u16, u16) {
+fn parse_two_hex(twohex: &str) -> Result<(u16, u16), Error> {
     let parts: Vec<&str> = twohex.split(",").collect();
-    (u16::from_str_radix(parts[0], 16).unwrap(),
-     u16::from_str_radix(parts[1], 16).unwrap())
+    if parts.len() != 2 {
+        return Err(Error::InvalidArgument(format!("expected vendor,product, got {:?}", twohex)));
+    }
+    match (u16::from_str_radix(parts[0], 16), u16::from_str_radix(parts[1], 16)) {
+        (Ok(vendor), Ok(product)) => Ok((vendor, product)),
+        _ => Err(Error::InvalidArgument(format!("expected hex vendor,product, got {:?}", twohex))),
+    }
 }
 
-fn parse_two_ints(twoints: &str) -> (u8, u8) {
+fn parse_two_ints(twoints: &str) -> Result<(u8, u8), Error> {
     let parts: Vec<&str> = twoints.split(",").collect();
-    (parts[0].parse::<u8>().unwrap(), parts[1].parse::<u8>().unwrap())
+    if parts.len() != 2 {
+        return Err(Error::InvalidArgument(format!("expected bus,address, got {:?}", twoints)));
+    }
+    match (parts[0].parse::<u8>(), parts[1].parse::<u8>()) {
+        (Ok(bus), Ok(address)) => Ok((bus, address)),
+        _ => Err(Error::InvalidArgument(format!("expected decimal bus,address, got {:?}", twoints))),
+    }
 }
 
-fn parse_vp(vps: Vec<&str>) -> HashSet<(u16, u16)> {
+fn parse_vp(vps: Vec<&str>) -> Result<HashSet<(u16, u16)>, Error> {
     let mut vpset = HashSet::<(u16, u16)>::new();
     for vp in vps.iter() {
-        vpset.insert(parse_two_hex(vp));
+        vpset.insert(parse_two_hex(vp)?);
     }
-    vpset
+    Ok(vpset)
 }
 
-fn parse_addrs(addrs: Vec<&str>) -> HashSet<(u8, u8)> {
+fn parse_addrs(addrs: Vec<&str>) -> Result<HashSet<(u8, u8)>, Error> {
     let mut addrset = HashSet::<(u8, u8)>::new();
     for addr in addrs.iter() {
-        addrset.insert(parse_two_ints(addr));
+        addrset.insert(parse_two_ints(addr)?);
+    }
+    Ok(addrset)
+}
+
+/* a device a filter picked but couldn't open (busy, no permission, ...) */
+#[derive(Debug)]
+pub struct OpenFailure {
+    pub identity: TransportIdentity,
+    pub error: Error,
+}
+
+impl fmt::Display for OpenFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "b{:03} d{:03} v{:04x} p{:04x}: {}",
+               self.identity.bus_number, self.identity.address,
+               self.identity.vendor_id, self.identity.product_id, self.error)
+    }
+}
+
+/* what a device filter came back with: one stick that won't open doesn't cost the rest */
+pub struct Opened<T> {
+    pub devices: Vec<T>,
+    pub failed: Vec<OpenFailure>,
+}
+
+impl<T> Opened<T> {
+
+    fn new() -> Opened<T> {
+        Opened{devices: Vec::new(), failed: Vec::new()}
+    }
+
+    fn push(&mut self, identity: TransportIdentity, opened: Result<T, Error>) {
+        match opened {
+            Ok(dev) => self.devices.push(dev),
+            Err(err) => self.failed.push(OpenFailure{identity: identity, error: err}),
+        }
+    }
+}
+
+fn usb_identity(device: &libusb::Device, device_desc: &libusb::DeviceDescriptor) -> TransportIdentity {
+    TransportIdentity{
+        bus_number: device.bus_number(),
+        address: device.address(),
+        vendor_id: device_desc.vendor_id(),
+        product_id: device_desc.product_id(),
     }
-    addrset
 }
 
 pub fn rfcat_filter<'a>(
@@ -675,10 +2033,10 @@ pub fn rfcat_filter<'a>(
     usb_addresses: Option<Vec<&str>>,
     usb_vendor_products: Option<Vec<&str>>,
     /* TODO: SPI et al */
-) -> Vec<RFCatDevice<'a>> {
-    let mut rfcat_list: Vec<RFCatDevice> = Vec::new();
-    let mut picked_addresses: bool;
-    let mut addresses: HashSet<(u8, u8)>;
+) -> Result<Opened<RFCatDevice<'a>>, Error> {
+    let mut rfcat_list = Opened::<RFCatDevice>::new();
+    let picked_addresses: bool;
+    let addresses: HashSet<(u8, u8)>;
     match usb_addresses {
         None => {
             picked_addresses = false;
@@ -686,12 +2044,12 @@ pub fn rfcat_filter<'a>(
         }
         Some(addrs) => {
             picked_addresses = true;
-            addresses = parse_addrs(addrs);
+            addresses = parse_addrs(addrs)?;
         }
     }
 
-    let mut picked_vps: bool;
-    let mut vps: HashSet<(u16, u16)>;
+    let picked_vps: bool;
+    let vps: HashSet<(u16, u16)>;
     match usb_vendor_products {
         None => {
             picked_vps = false;
@@ -700,24 +2058,16 @@ pub fn rfcat_filter<'a>(
         }
         Some(uvps) => {
             picked_vps = true;
-            vps = parse_vp(uvps);
+            vps = parse_vp(uvps)?;
         }
     }
     if let Some(ctx) = usb_context {
-        let usb_devices = match ctx.devices() {
-            Ok(devs) => devs,
-            Err(err) => {
-                println!("Error: {}", err);
-                return rfcat_list;
-            }
-        };
+        let usb_devices = ctx.devices()?;
         for device in usb_devices.iter() {
+            // whatever else is on the bus is none of our business
             let device_desc = match device.device_descriptor() {
                 Ok(k) => k,
-                Err(err) => {
-                    println!("Error getting descriptor: {}", err);
-                    continue
-                }
+                Err(_) => continue,
             };
             /* Address matching */
             if picked_addresses {
@@ -738,13 +2088,12 @@ pub fn rfcat_filter<'a>(
                     continue
                 }
             }
-            match RFCatDevice::make_from_libusb(device, device_desc) {
-                Ok(rfcat_dev) => { rfcat_list.push(rfcat_dev) },
-                Err(_) => {},
-            }
+            // picked (or standard) but won't open: say so rather than leave it out
+            let identity = usb_identity(&device, &device_desc);
+            rfcat_list.push(identity, RFCatDevice::make_from_libusb(device, device_desc));
         }
     }
-    rfcat_list
+    Ok(rfcat_list)
 }
 
 pub struct RFCatBLDevice<'a> {
@@ -756,40 +2105,116 @@ pub struct RFCatBLDevice<'a> {
     descriptor: libusb::DeviceDescriptor,
     language: Option<libusb::Language>,
     timeout: Duration,
-    #[allow(dead_code)]
     max_input_size: u16,
-    #[allow(dead_code)]
     in_endpoint_address: u8,
-    #[allow(dead_code)]
     out_endpoint_address: u8,
+    // the CDC data interface the bulk endpoints live on, and whether we hold it
+    data_interface: u8,
+    claimed: bool,
+    // bootloader output not consumed yet
+    rx: VecDeque<u8>,
 }
 
 impl<'a> RFCatBLDevice<'a> {
 
-    pub fn manufacturer(&self) -> Result<String, libusb::Error> {
-        match self.handle.read_manufacturer_string(self.language.unwrap(), &self.descriptor, self.timeout) {
+    pub fn make_from_libusb(
+        device: libusb::Device<'a>,
+        device_desc: libusb::DeviceDescriptor,
+    ) -> Result<RFCatBLDevice<'a>, Error> {
+        let mut handle = device.open()?;
+        let bus_number = device.bus_number();
+        let address = device.address();
+        let vendor_id = device_desc.vendor_id();
+        let product_id = device_desc.product_id();
+        let timeout = Duration::from_secs(1);
+        let langs = handle.read_languages(timeout)?;
+        let language: Option<libusb::Language>;
+        if langs.len() > 0 {
+            language = Some(langs[0]);
+        } else {
+            language = None;
+        }
+        let mut in_max_size: u16 = 64;
+        let mut in_ep_addr: u8 = 0;
+        let mut out_ep_addr: u8 = 0;
+        let mut data_iface: u8 = 0;
+        handle.reset()?;
+        for n in 0..device_desc.num_configurations() {
+            let config_desc = match device.config_descriptor(n) {
+                Ok(k) => k,
+                Err(_) => continue,
+            };
+            for interface in config_desc.interfaces() {
+                for interface_desc in interface.descriptors() {
+                    for endpoint_desc in interface_desc.endpoint_descriptors() {
+                        if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
+                             endpoint_desc.direction() == libusb::Direction::In {
+                            in_ep_addr = endpoint_desc.address();
+                            in_max_size = endpoint_desc.max_packet_size();
+                            data_iface = interface_desc.interface_number();
+                        }
+                        if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
+                             endpoint_desc.direction() == libusb::Direction::Out {
+                            out_ep_addr = endpoint_desc.address();
+                        }
+
+                    }
+                }
+            }
+        }
+        Ok(RFCatBLDevice{
+            bus_number: bus_number,
+            address: address,
+            vendor_id: vendor_id,
+            product_id: product_id,
+            handle: handle,
+            descriptor: device_desc,
+            language: language,
+            timeout: timeout,
+            max_input_size: in_max_size,
+            in_endpoint_address: in_ep_addr,
+            out_endpoint_address: out_ep_addr,
+            data_interface: data_iface,
+            claimed: false,
+            rx: VecDeque::new(),
+        })
+    }
+
+    pub fn manufacturer(&self) -> Result<String, Error> {
+        let language = match self.language {
+            Some(language) => language,
+            None => {
+                return Err(Error::Unsupported("descriptor strings"));
+            },
+        };
+        match self.handle.read_manufacturer_string(language, &self.descriptor, self.timeout) {
             Ok(mstr) => {
                 return Ok(mstr);
             },
             Err(err) => {
-                return Err(err);
+                return Err(err.into());
             }
         }
     }
 
-    pub fn product(&self) -> Result<String, libusb::Error> {
-        match self.handle.read_product_string(self.language.unwrap(), &self.descriptor, self.timeout) {
+    pub fn product(&self) -> Result<String, Error> {
+        let language = match self.language {
+            Some(language) => language,
+            None => {
+                return Err(Error::Unsupported("descriptor strings"));
+            },
+        };
+        match self.handle.read_product_string(language, &self.descriptor, self.timeout) {
             Ok(pstr) => {
                 return Ok(pstr);
             },
             Err(err) => {
-                return Err(err);
+                return Err(err.into());
             }
         }
     }
 }
 
-#[allow(dead_code)]
 fn is_rfcat_bootloader(usbdd: &libusb::DeviceDescriptor) -> bool {
     match (usbdd.vendor_id(), usbdd.product_id()) {
         (0x1d50, 0x6049) => true, 
@@ -800,107 +2225,92 @@ fn is_rfcat_bootloader(usbdd: &libusb::DeviceDescriptor) -> bool {
     }
 }
 
-pub fn all_rfcatbls(context: &libusb::Context) -> Vec<RFCatBLDevice> {
-    let mut rfcatbl_list: Vec<RFCatBLDevice> = Vec::new();
-    let devices = match context.devices() {
-        Ok(k) => k,
-        Err(err) => {
-            println!("Error: {}", err);
-            return rfcatbl_list;
-        },
-    };
-    for device in devices.iter() {
+pub fn all_rfcatbls<'a>(context: &'a libusb::Context) -> Result<Opened<RFCatBLDevice<'a>>, Error> {
+    let mut rfcatbl_list = Opened::<RFCatBLDevice>::new();
+    for device in context.devices()?.iter() {
         let device_desc = match device.device_descriptor() {
             Ok(k) => k,
-            Err(err) => {
-                println!("Error getting descriptor: {}", err);
-                continue
-            }
+            Err(_) => continue,
         };
         if is_rfcat_bootloader(&device_desc) {
-            let mut handle = match device.open() {
-                Ok(k) => k,
-                Err(err) => {
-                    println!("Error opening device: {}", err);
-                    continue
-                }
-            };
-            let bus_number = device.bus_number();
-            let address = device.address();
-            let vendor_id = device_desc.vendor_id();
-            let product_id = device_desc.product_id();
-            let timeout = Duration::from_secs(1);
-            let langs = match handle.read_languages(timeout) {
-                Ok(k) => k,
-                Err(err) => {
-                    println!("Error in reading languages: {}", err);
-                    continue
-                }
-            };
-            let language: Option<libusb::Language>;
-            if langs.len() > 0 {
-                language = Some(langs[0]);
-            } else {
-                language = None;
-            }
-            let mut in_max_size: u16 = 64;
-            let mut in_ep_addr: u8 = 0;
-            let mut out_ep_addr: u8 = 0;
-            match handle.reset() {
-                Ok(k) => k,
-                Err(err) => {
-                    println!("Error resetting device: {}", err);
-                    continue
-                }
-            };
-            for n in 0..device_desc.num_configurations() {
-                let config_desc = match device.config_descriptor(n) {
-                    Ok(k) => k,
-                    Err(_) => continue,
-                };
-                for interface in config_desc.interfaces() {
-                    for interface_desc in interface.descriptors() {
-                        for endpoint_desc in interface_desc.endpoint_descriptors() {
-                            if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
-                                 endpoint_desc.direction() == libusb::Direction::In {
-                                in_ep_addr = endpoint_desc.address();
-                                in_max_size = endpoint_desc.max_packet_size();
-                            }
-                            if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
-                                 endpoint_desc.direction() == libusb::Direction::Out {
-                                out_ep_addr = endpoint_desc.address();
-                            }
+            let identity = usb_identity(&device, &device_desc);
+            rfcatbl_list.push(identity, RFCatBLDevice::make_from_libusb(device, device_desc));
+        }
+    }
+    Ok(rfcatbl_list)
+}
 
-                        }
-                    }
-                }
+/* like rfcat_filter, for RFCats in bootloader mode */
+pub fn rfcatbl_filter<'a>(
+    usb_context: &'a libusb::Context,
+    usb_addresses: Option<Vec<&str>>,
+    usb_vendor_products: Option<Vec<&str>>,
+) -> Result<Opened<RFCatBLDevice<'a>>, Error> {
+    let addresses = match usb_addresses {
+        None => None,
+        Some(addrs) => Some(parse_addrs(addrs)?),
+    };
+    let vps = match usb_vendor_products {
+        None => None,
+        Some(uvps) => Some(parse_vp(uvps)?),
+    };
+    let mut rfcatbl_list = Opened::<RFCatBLDevice>::new();
+    for device in usb_context.devices()?.iter() {
+        let device_desc = match device.device_descriptor() {
+            Ok(k) => k,
+            Err(_) => continue,
+        };
+        if let Some(addresses) = &addresses {
+            if !addresses.contains(&(device.bus_number(), device.address())) {
+                continue
             }
-            let exdev = RFCatBLDevice{
-                bus_number: bus_number,
-                address: address,
-                vendor_id: vendor_id,
-                product_id: product_id,
-                handle: handle,
-                descriptor: device_desc,
-                language: language,
-                timeout: timeout,
-                max_input_size: in_max_size,
-                in_endpoint_address: in_ep_addr,
-                out_endpoint_address: out_ep_addr,
-            };
-            rfcatbl_list.push(exdev);
         }
+        let wanted = match &vps {
+            Some(vps) => vps.contains(&(device_desc.vendor_id(), device_desc.product_id())),
+            None => is_rfcat_bootloader(&device_desc),
+        };
+        if !wanted {
+            continue
+        }
+        let identity = usb_identity(&device, &device_desc);
+        rfcatbl_list.push(identity, RFCatBLDevice::make_from_libusb(device, device_desc));
     }
-    rfcatbl_list
+    Ok(rfcatbl_list)
+}
+
+/* rfcat_filter on the shared context, for callers that need to keep (or move) the devices */
+pub fn open_rfcats(
+    usb_addresses: Option<Vec<&str>>,
+    usb_vendor_products: Option<Vec<&str>>,
+) -> Result<Opened<OwnedRFCatDevice>, Error> {
+    rfcat_filter(Some(shared_context()?), usb_addresses, usb_vendor_products)
+}
+
+/* all_rfcatbls on the shared context */
+pub fn open_rfcatbls() -> Result<Opened<OwnedRFCatBLDevice>, Error> {
+    all_rfcatbls(shared_context()?)
 }
 
 // BEGIN dogscience and copypasting
 
+// 24MHz crystal
+pub const OSCILLATOR_HZ: u64 = 24_000_000;
+
 // enum BandLimitsMHz {
 //     B300 = (281, 361),
 //     B400 = (378, 749),
 //     B900 = (749, 962),
 // }
+// what the YARD Stick One tunes in practice; the 400 band stops at 481, not 749
+const BAND_LIMITS_MHZ: [(u64, u64); 3] = [
+    (281, 361),
+    (378, 481),
+    (749, 962),
+];
+
+// FSCAL2 VCO selection
+const FSCAL2_LOW_VCO: u8 = 0x0A;
+const FSCAL2_HIGH_VCO: u8 = 0x2A;
 
 enum BandTransitionsMHz {
     BT400 = 369,
@@ -913,17 +2323,91 @@ enum VCOTransitionsMHz {
     VT900 = 848,
 }
 
-enum SyncM {
+// MDMCFG2 MOD_FORMAT
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ModulationFormat {
+    TwoFsk = 0x00,
+    Gfsk = 0x10,
+    AskOok = 0x30,
+    Msk = 0x70,
+    UNKNOWN = 0xFF,
+}
+
+impl From<u8> for ModulationFormat {
+    fn from(value: u8) -> Self {
+        match value & registers::MDMCFG2_MOD_FORMAT.mask {
+            0x00 => ModulationFormat::TwoFsk,
+            0x10 => ModulationFormat::Gfsk,
+            0x30 => ModulationFormat::AskOok,
+            0x70 => ModulationFormat::Msk,
+            _ => ModulationFormat::UNKNOWN,
+        }
+    }
+}
+
+// MDMCFG2 SYNC_MODE
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum SyncM {
     SMNone = 0,
     SM15of16 = 1,
     SM16of16 = 2,
-    SM30of32 = 7,
+    SM30of32 = 3,
+    SMCarrier = 4,
+    SMCarrier15of16 = 5,
+    SMCarrier16of16 = 6,
+    SMCarrier30of32 = 7,
 }
 
-// 0xDF3B: MARCSTATE - Main Radio Control State Machine State
-// #define MARCSTATE_MARC_STATE              0x1F
+impl From<u8> for SyncM {
+    fn from(value: u8) -> Self {
+        match registers::MDMCFG2_SYNC_MODE.get(value) {
+            0 => SyncM::SMNone,
+            1 => SyncM::SM15of16,
+            2 => SyncM::SM16of16,
+            3 => SyncM::SM30of32,
+            4 => SyncM::SMCarrier,
+            5 => SyncM::SMCarrier15of16,
+            6 => SyncM::SMCarrier16of16,
+            _ => SyncM::SMCarrier30of32,
+        }
+    }
+}
 
-enum MainRadioControlState {
+// PKTCTRL0 LENGTH_CONFIG (+ PKTLEN)
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum PacketLength {
+    Fixed(u8),
+    // PKTLEN is the maximum length allowed
+    Variable(u8),
+    Infinite,
+}
+
+// PKTCTRL1 ADR_CHK
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum AddressCheck {
+    NoCheck = 0,
+    NoBroadcast = 1,
+    Broadcast0 = 2,
+    Broadcast0And255 = 3,
+}
+
+impl From<u8> for AddressCheck {
+    fn from(value: u8) -> Self {
+        match registers::PKTCTRL1_ADR_CHK.get(value) {
+            0 => AddressCheck::NoCheck,
+            1 => AddressCheck::NoBroadcast,
+            2 => AddressCheck::Broadcast0,
+            _ => AddressCheck::Broadcast0And255,
+        }
+    }
+}
+
+// MDMCFG1 NUM_PREAMBLE, in bytes
+const PREAMBLE_BYTES: [u8; 8] = [2, 3, 4, 6, 8, 12, 16, 24];
+
+// 0xDF3B: MARCSTATE - Main Radio Control State Machine State
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum MainRadioControlState {
     Sleep                  = 0x00,
     Idle                   = 0x01,
     VcoOnMc               = 0x03,
@@ -946,14 +2430,251 @@ enum MainRadioControlState {
     TxEnd                 = 0x14,
     RxTxSwitch            = 0x15,
     TxUnderflow           = 0x16,
+    UNKNOWN                = 0xFF,
 }
 
-// 0xDF3C Packetstatus register
-enum PacketStatusRegister {
-    SFD                     = 0x08,
-    CCA                     = 0x10,
-    PQTREACHED              = 0x20,
-    CS                      = 0x40,
-    CRCOK                   = 0x80,
+impl From<u8> for MainRadioControlState {
+    fn from(value: u8) -> Self {
+        match registers::MARCSTATE_MARC_STATE.get(value) {
+            0x00 => MainRadioControlState::Sleep,
+            0x01 => MainRadioControlState::Idle,
+            0x03 => MainRadioControlState::VcoOnMc,
+            0x04 => MainRadioControlState::RegOnMc,
+            0x05 => MainRadioControlState::ManCal,
+            0x06 => MainRadioControlState::VcoOn,
+            0x07 => MainRadioControlState::RegOn,
+            0x08 => MainRadioControlState::StartCal,
+            0x09 => MainRadioControlState::BwBoost,
+            0x0A => MainRadioControlState::FsLock,
+            0x0B => MainRadioControlState::IfadCon,
+            0x0C => MainRadioControlState::EndCal,
+            0x0D => MainRadioControlState::Rx,
+            0x0E => MainRadioControlState::RxEnd,
+            0x0F => MainRadioControlState::RxRst,
+            0x10 => MainRadioControlState::TxRxSwitch,
+            0x11 => MainRadioControlState::RxOverflow,
+            0x12 => MainRadioControlState::FstXOn,
+            0x13 => MainRadioControlState::Tx,
+            0x14 => MainRadioControlState::TxEnd,
+            0x15 => MainRadioControlState::RxTxSwitch,
+            0x16 => MainRadioControlState::TxUnderflow,
+            _ => MainRadioControlState::UNKNOWN,
+        }
+    }
 }
 
+impl MainRadioControlState {
+
+    /* the strobe that gets the radio back to this state after an idle (rfcat's MARC_STATE_MAPPINGS) */
+    pub fn return_strobe(&self) -> RfState {
+        match self {
+            MainRadioControlState::Rx |
+            MainRadioControlState::RxEnd |
+            MainRadioControlState::RxRst => RfState::SRX,
+            MainRadioControlState::FstXOn => RfState::SFSTXON,
+            MainRadioControlState::Tx |
+            MainRadioControlState::TxEnd => RfState::STX,
+            _ => RfState::SIDLE,
+        }
+    }
+
+    /*
+     * the state a strobe settles the radio into, for the strobes that settle
+     * somewhere (STX with nothing to send is in TX_UNDERFLOW before we can look)
+     */
+    pub fn entered_by(strobe: RfState) -> Option<MainRadioControlState> {
+        match strobe {
+            RfState::SIDLE => Some(MainRadioControlState::Idle),
+            RfState::SRX => Some(MainRadioControlState::Rx),
+            RfState::SFSTXON => Some(MainRadioControlState::FstXOn),
+            _ => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(mbx: u8, cmd: u8, payload: &[u8]) -> Vec<u8> {
+        let plen = (payload.len() as u16).to_le_bytes();
+        let mut frame = vec![FRAME_SYNC, mbx, cmd, plen[0], plen[1]];
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    #[test]
+    fn packet_from_bytes() {
+        let pkt = RfCatPacket::from_bytes(frame(0xff, 0x80, &[1, 2, 3])).unwrap();
+        assert_eq!((pkt.mbx, pkt.cmd, pkt.payload), (AppMailbox::AppSystem, 0x80, vec![1, 2, 3]));
+        assert_eq!(RfCatPacket::from_bytes(vec![0x40, 0xff, 0x80]).unwrap_err(),
+                   FrameError::Truncated{expected: 5, got: 3});
+        assert_eq!(RfCatPacket::from_bytes(vec![0x40, 0xff, 0x80, 0x02, 0x00, 0x01]).unwrap_err(),
+                   FrameError::Truncated{expected: 7, got: 6});
+        assert_eq!(RfCatPacket::from_bytes(vec![0x41, 0xff, 0x80, 0x00, 0x00]).unwrap_err(),
+                   FrameError::BadSync(0x41));
+        assert_eq!(RfCatPacket::from_bytes(vec![0x40, 0xff, 0x80, 0x00, 0x00, 0x00]).unwrap_err(),
+                   FrameError::TrailingBytes(1));
+    }
+
+    #[test]
+    fn decoder_truncated() {
+        let mut decoder = FrameDecoder::new();
+        let bytes = frame(0xff, 0x80, &[1, 2, 3, 4]);
+        decoder.feed(&bytes[..3]);
+        assert_eq!(decoder.next_packet().unwrap(), None);
+        decoder.feed(&bytes[3..7]);
+        assert_eq!(decoder.next_packet().unwrap(), None);
+        assert_eq!(decoder.pending(), 7);
+        assert_eq!(decoder.skipped(), 0);
+    }
+
+    #[test]
+    fn decoder_reassembles_reads() {
+        let mut decoder = FrameDecoder::new();
+        let mut bytes = frame(0x42, 0x02, &(0..100).collect::<Vec<u8>>());
+        bytes.extend(frame(0xff, 0x82, b"YARDSTICKONE r0543"));
+        let mut packets = Vec::new();
+        for read in bytes.chunks(7) {
+            decoder.feed(read);
+            while let Some(pkt) = decoder.next_packet().unwrap() {
+                packets.push(pkt);
+            }
+        }
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].payload, (0..100).collect::<Vec<u8>>());
+        assert_eq!((packets[1].mbx, packets[1].payload.as_slice()), (AppMailbox::AppSystem, &b"YARDSTICKONE r0543"[..]));
+        assert_eq!(decoder.pending(), 0);
+    }
+
+    #[test]
+    fn decoder_resyncs_past_garbage() {
+        let mut decoder = FrameDecoder::new();
+        decoder.feed(&[0x00, 0x13, 0x37]);
+        // '@' then a length no frame has
+        decoder.feed(&[0x40, 0xff, 0x80, 0xff, 0xff]);
+        decoder.feed(&frame(0xff, 0x80, &[0xaa]));
+        let pkt = decoder.next_packet().unwrap().unwrap();
+        assert_eq!(pkt.payload, vec![0xaa]);
+        assert_eq!(decoder.skipped(), 3 + 5);
+    }
+
+    #[test]
+    fn decoder_frames_unknown_mailboxes() {
+        let mut decoder = FrameDecoder::new();
+        // an unknown mailbox whose payload looks like a frame header of its own
+        decoder.feed(&frame(0x43, 0x01, &frame(0xff, 0x80, &[0x55])));
+        decoder.feed(&frame(0xff, 0x80, &[0xaa]));
+        let pkt = decoder.next_packet().unwrap().unwrap();
+        assert_eq!((pkt.mbx, pkt.payload.len()), (AppMailbox::UNKNOWN, 6));
+        let pkt = decoder.next_packet().unwrap().unwrap();
+        assert_eq!(pkt.payload, vec![0xaa]);
+        assert_eq!(decoder.next_packet().unwrap(), None);
+        assert_eq!(decoder.skipped(), 0);
+    }
+
+    #[test]
+    fn cc_registers_match_register_table() {
+        // one register per radio address, and CCRegisters names the same ones
+        let mut seen = Vec::new();
+        for reg in registers::radio() {
+            let addr = reg.xdata.unwrap();
+            assert!(!seen.contains(&addr), "0x{:04x} listed twice", addr);
+            seen.push(addr);
+        }
+        for &(addr, name) in &[(CCRegisters::Sync1 as u16, "SYNC1"), (CCRegisters::PktCtrl0 as u16, "PKTCTRL0"),
+                               (CCRegisters::MdmCfg2 as u16, "MDMCFG2"), (CCRegisters::FrEnd0 as u16, "FREND0"),
+                               (CCRegisters::MarcState as u16, "MARCSTATE"), (CCRegisters::VcoVcDac as u16, "VCO_VC_DAC")] {
+            assert_eq!(registers::by_xdata(addr).map(|r| r.name), Some(name));
+        }
+    }
+
+    #[test]
+    fn status_register_flags() {
+        assert_eq!(registers::PKTSTATUS.decode(0xa9), vec!["CRC_OK", "PQT_REACHED", "SFD", "GDO0"]);
+        assert_eq!(registers::PKTSTATUS.decode(0x54), vec!["CS", "CCA", "GDO2"]);
+        assert_eq!(registers::LQI.decode(0x9e), vec!["CRC_OK", "LQI_EST=30"]);
+        assert_eq!(registers::LQI.decode(0x1e), vec!["LQI_EST=30"]);
+    }
+
+    #[test]
+    fn frequency_registers() {
+        let mut config = RadioConfig::default();
+        assert_eq!(config.set_frequency(433_920_000).unwrap(), 433_920_044);
+        assert_eq!((config.freq2, config.freq1, config.freq0, config.fscal2), (0x12, 0x14, 0x7b, FSCAL2_HIGH_VCO));
+        assert_eq!(config.set_frequency(915_000_000).unwrap(), 915_000_000);
+        assert_eq!((config.freq2, config.freq1, config.freq0, config.fscal2), (0x26, 0x20, 0x00, FSCAL2_HIGH_VCO));
+
+        // the low VCO below each band's transition, the high one above it
+        for &(below, above) in &[(317_900_000, 318_100_000), (423_900_000, 424_100_000), (847_900_000, 848_100_000)] {
+            config.set_frequency(below).unwrap();
+            assert_eq!(config.fscal2, FSCAL2_LOW_VCO, "{} Hz", below);
+            config.set_frequency(above).unwrap();
+            assert_eq!(config.fscal2, FSCAL2_HIGH_VCO, "{} Hz", above);
+        }
+
+        let before = config;
+        for &hz in &[200_000_000, 370_000_000, 500_000_000, 700_000_000, 1_000_000_000] {
+            match config.set_frequency(hz) {
+                Err(Error::InvalidArgument(_)) => (),
+                other => panic!("{} Hz: {:?}", hz, other),
+            }
+        }
+        assert_eq!(config, before);
+    }
+
+    #[test]
+    fn device_set_frequency() {
+        let mock = mock::MockTransport::new();
+        let rfcat = RFCatDevice::from_transport(Box::new(mock.clone()));
+        mock.set_memory(CCRegisters::MarcState as u16, &[MainRadioControlState::Rx as u8]);
+        assert_eq!(rfcat.set_frequency(433_920_000).unwrap(), 433_920_044);
+        assert_eq!(mock.memory(CCRegisters::Freq2 as u16, 3), vec![0x12, 0x14, 0x7b]);
+        assert_eq!(mock.memory(CCRegisters::FsCal2 as u16, 1), vec![FSCAL2_HIGH_VCO]);
+        // idle, recalibrate for the new frequency, back to RX
+        assert_eq!(mock.strobes(), vec![RfState::SIDLE as u8, RfState::SCAL as u8, RfState::SRX as u8]);
+        assert_eq!(rfcat.get_frequency().unwrap(), 433_920_044);
+
+        // out of band never gets as far as the radio
+        let sent = mock.sent().len();
+        assert!(rfcat.set_frequency(500_000_000).is_err());
+        assert_eq!(mock.sent().len(), sent);
+    }
+
+    #[test]
+    fn radio_config_fields() {
+        let mut config = RadioConfig::from_bytes(&[0; RADIO_CONFIG_LEN as usize]).unwrap();
+        config.set_modulation(ModulationFormat::AskOok).unwrap();
+        config.set_sync_mode(SyncM::SMCarrier30of32);
+        config.set_packet_length(PacketLength::Variable(60));
+        config.set_crc(true);
+        config.set_address_filter(AddressCheck::Broadcast0And255, 0x12);
+        config.set_append_status(true);
+        assert_eq!(config.set_preamble_length(5).unwrap(), 6);
+        assert_eq!((config.mdmcfg2, config.frend0, config.pktctrl0, config.pktctrl1, config.mdmcfg1),
+                   (0x37, 0x01, 0x05, 0x07, 0x30));
+        assert_eq!(config.modulation(), ModulationFormat::AskOok);
+        assert_eq!(config.sync_mode(), SyncM::SMCarrier30of32);
+        assert_eq!(config.packet_length(), PacketLength::Variable(60));
+        assert!(config.crc() && !config.whitening() && config.append_status());
+        assert_eq!(config.address_filter(), (AddressCheck::Broadcast0And255, 0x12));
+        assert_eq!(config.preamble_length(), 6);
+
+        config.set_modulation(ModulationFormat::Gfsk).unwrap();
+        config.set_crc(false);
+        config.set_whitening(true);
+        assert_eq!((config.mdmcfg2, config.frend0, config.pktctrl0), (0x17, 0x00, 0x41));
+
+        // the exponent and mantissa fields land in their own bits
+        config.mdmcfg4 = 0x5a;
+        config.deviatn = 0x47;
+        let rate = config.data_rate();
+        let bw = config.channel_bandwidth();
+        let dev = config.deviation();
+        config.set_data_rate(rate).unwrap();
+        config.set_channel_bandwidth(bw).unwrap();
+        config.set_deviation(dev).unwrap();
+        assert_eq!((config.mdmcfg4, config.deviatn), (0x5a, 0x47));
+    }
+}
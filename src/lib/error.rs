@@ -0,0 +1,86 @@
+use std::fmt;
+use std::string::FromUtf8Error;
+
+use crate::usbbl::BootloaderStatus;
+use crate::{AppMailbox, FrameError, NicReturnCode};
+
+/* everything that can go wrong talking to an RFCat, from the wire on up */
+#[derive(Debug)]
+pub enum Error {
+    // the transport (libusb, for now) gave up
+    Transport(libusb::Error),
+    // the device went away (unplugged, reset, off to the bootloader)
+    Disconnected,
+    // nothing came back in time
+    Timeout,
+    // bytes came back, but not as a frame
+    Framing(FrameError),
+    // a frame came back, but not the one we asked for (or not shaped like it)
+    UnexpectedReply { mbx: AppMailbox, cmd: u8 },
+    // the firmware said no
+    Firmware(NicReturnCode),
+    // CC-Bootloader didn't like a record
+    Bootloader(BootloaderStatus),
+    // CC-Bootloader said something we couldn't make sense of
+    BadBootloaderReply(String),
+    // a string from the firmware wasn't UTF-8
+    InvalidUtf8(FromUtf8Error),
+    // wrote it, read it back, and it didn't stick
+    VerifyFailed { addr: u16 },
+    // the caller asked for something out of range
+    InvalidArgument(String),
+    // this device / transport can't do that
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Transport(err) => write!(f, "transport error: {}", err),
+            Error::Disconnected => write!(f, "device disconnected"),
+            Error::Timeout => write!(f, "timed out"),
+            Error::Framing(err) => write!(f, "framing error: {}", err),
+            Error::UnexpectedReply{mbx, cmd} => write!(f, "unexpected reply (mailbox 0x{:02x}, command 0x{:02x})", *mbx as u8, cmd),
+            Error::Firmware(code) => write!(f, "firmware error: {:?} (0x{:02x})", code, *code as u8),
+            Error::Bootloader(status) => write!(f, "bootloader error: {:?} (0x{:02x})", status, *status as u8),
+            Error::BadBootloaderReply(line) => write!(f, "unexpected bootloader reply: {:?}", line),
+            Error::InvalidUtf8(err) => write!(f, "invalid UTF-8 from firmware: {}", err),
+            Error::VerifyFailed{addr} => write!(f, "readback mismatch at 0x{:04x}", addr),
+            Error::InvalidArgument(what) => write!(f, "invalid argument: {}", what),
+            Error::Unsupported(what) => write!(f, "unsupported: {}", what),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(err) => Some(err),
+            Error::Framing(err) => Some(err),
+            Error::InvalidUtf8(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<libusb::Error> for Error {
+    fn from(err: libusb::Error) -> Self {
+        match err {
+            libusb::Error::Timeout => Error::Timeout,
+            libusb::Error::NoDevice => Error::Disconnected,
+            _ => Error::Transport(err),
+        }
+    }
+}
+
+impl From<FrameError> for Error {
+    fn from(err: FrameError) -> Self {
+        Error::Framing(err)
+    }
+}
+
+impl From<FromUtf8Error> for Error {
+    fn from(err: FromUtf8Error) -> Self {
+        Error::InvalidUtf8(err)
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::usbbl::FLASH_PAGE_SIZE;
+
+// Intel HEX record types, plus the ones CC-Bootloader adds for itself
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum RecordType {
+    Data = 0x00,
+    EndOfFile = 0x01,
+    ExtendedSegmentAddress = 0x02,
+    StartSegmentAddress = 0x03,
+    ExtendedLinearAddress = 0x04,
+    StartLinearAddress = 0x05,
+    // CC-Bootloader
+    CrcCheck = 0x20,
+    ResetBootloader = 0x22,
+    EraseAll = 0x23,
+    ErasePage = 0x24,
+    ReadPage = 0x25,
+    UNKNOWN = 0xff,
+}
+
+impl From<u8> for RecordType {
+    fn from(orig: u8) -> Self {
+        match orig {
+            0x00 => RecordType::Data,
+            0x01 => RecordType::EndOfFile,
+            0x02 => RecordType::ExtendedSegmentAddress,
+            0x03 => RecordType::StartSegmentAddress,
+            0x04 => RecordType::ExtendedLinearAddress,
+            0x05 => RecordType::StartLinearAddress,
+            0x20 => RecordType::CrcCheck,
+            0x22 => RecordType::ResetBootloader,
+            0x23 => RecordType::EraseAll,
+            0x24 => RecordType::ErasePage,
+            0x25 => RecordType::ReadPage,
+            _ => RecordType::UNKNOWN,
+        }
+    }
+}
+
+/* one ":LLAAAATT<data>CC" line */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HexRecord {
+    pub record_type: RecordType,
+    pub address: u16,
+    pub data: Vec<u8>,
+}
+
+impl HexRecord {
+
+    pub fn new(record_type: RecordType, address: u16, data: Vec<u8>) -> HexRecord {
+        HexRecord{
+            record_type: record_type,
+            address: address,
+            data: data,
+        }
+    }
+
+    pub fn parse(line: &str) -> Result<HexRecord, Error> {
+        let line = line.trim();
+        if !line.starts_with(':') || line.len() < 11 || line.len() % 2 != 1 {
+            return Err(Error::InvalidArgument(format!("not an Intel HEX record: {:?}", line)));
+        }
+        let mut bytes = Vec::<u8>::with_capacity(line.len() / 2);
+        for i in (1..line.len()).step_by(2) {
+            match u8::from_str_radix(&line[i..i + 2], 16) {
+                Ok(b) => bytes.push(b),
+                Err(_) => {
+                    return Err(Error::InvalidArgument(format!("not an Intel HEX record: {:?}", line)));
+                },
+            }
+        }
+        let len = bytes[0] as usize;
+        if bytes.len() != len + 5 {
+            return Err(Error::InvalidArgument(format!("Intel HEX record length mismatch: {:?}", line)));
+        }
+        if bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)) != 0 {
+            return Err(Error::InvalidArgument(format!("Intel HEX record checksum mismatch: {:?}", line)));
+        }
+        let record_type = RecordType::from(bytes[3]);
+        if record_type == RecordType::UNKNOWN {
+            return Err(Error::InvalidArgument(format!("unknown Intel HEX record type 0x{:02x}", bytes[3])));
+        }
+        Ok(HexRecord{
+            record_type: record_type,
+            address: u16::from_be_bytes([bytes[1], bytes[2]]),
+            data: bytes[4..4 + len].to_vec(),
+        })
+    }
+
+    pub fn to_line(&self) -> String {
+        let addr = self.address.to_be_bytes();
+        let mut bytes = vec![self.data.len() as u8, addr[0], addr[1], self.record_type as u8];
+        bytes.extend_from_slice(&self.data[..]);
+        let checksum = bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)).wrapping_neg();
+        bytes.push(checksum);
+        let mut line = String::with_capacity(1 + bytes.len() * 2);
+        line.push(':');
+        for b in bytes.iter() {
+            line.push_str(&format!("{:02X}", b));
+        }
+        line
+    }
+}
+
+/*
+ * a firmware image as flash pages: every page the image touches, 0xFF
+ * (erased flash) wherever the image has nothing to say
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FirmwareImage {
+    pages: BTreeMap<u16, Vec<u8>>,
+}
+
+impl FirmwareImage {
+
+    pub fn new() -> FirmwareImage {
+        FirmwareImage{pages: BTreeMap::new()}
+    }
+
+    /* the contents of an Intel HEX file (8051-sized: everything below 64K) */
+    pub fn parse(text: &str) -> Result<FirmwareImage, Error> {
+        let mut image = FirmwareImage::new();
+        let mut base: u32 = 0;
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                continue
+            }
+            let record = HexRecord::parse(line)?;
+            match record.record_type {
+                RecordType::Data => image.insert(base + record.address as u32, &record.data[..])?,
+                RecordType::EndOfFile => break,
+                RecordType::ExtendedSegmentAddress if record.data.len() == 2 => {
+                    base = (u16::from_be_bytes([record.data[0], record.data[1]]) as u32) << 4;
+                },
+                RecordType::ExtendedLinearAddress if record.data.len() == 2 => {
+                    base = (u16::from_be_bytes([record.data[0], record.data[1]]) as u32) << 16;
+                },
+                // entry points mean nothing to the CC
+                RecordType::StartSegmentAddress | RecordType::StartLinearAddress => (),
+                _ => {
+                    return Err(Error::InvalidArgument(format!("unexpected record in firmware image: {}", record.to_line())));
+                },
+            }
+        }
+        Ok(image)
+    }
+
+    /* a raw binary that starts at base */
+    pub fn from_binary(base: u16, bytes: &[u8]) -> Result<FirmwareImage, Error> {
+        let mut image = FirmwareImage::new();
+        image.insert(base as u32, bytes)?;
+        Ok(image)
+    }
+
+    /* lay data down starting at addr */
+    pub fn insert(&mut self, addr: u32, data: &[u8]) -> Result<(), Error> {
+        if addr as usize + data.len() > 0x10000 {
+            return Err(Error::InvalidArgument(format!("image data past 64K at 0x{:x}", addr)));
+        }
+        for (i, b) in data.iter().enumerate() {
+            let a = addr as usize + i;
+            let page = self.pages.entry((a / FLASH_PAGE_SIZE) as u16).or_insert_with(|| vec![0xff; FLASH_PAGE_SIZE]);
+            page[a % FLASH_PAGE_SIZE] = *b;
+        }
+        Ok(())
+    }
+
+    /* (page number, page contents), lowest page first */
+    pub fn pages(&self) -> impl Iterator<Item = (u16, &[u8])> {
+        self.pages.iter().map(|(page, data)| (*page, &data[..]))
+    }
+
+    pub fn page(&self, page: u16) -> Option<&[u8]> {
+        self.pages.get(&page).map(|data| &data[..])
+    }
+
+    /* put (or replace) a whole page */
+    pub fn set_page(&mut self, page: u16, data: &[u8]) -> Result<(), Error> {
+        if data.len() != FLASH_PAGE_SIZE || page as usize >= 0x10000 / FLASH_PAGE_SIZE {
+            return Err(Error::InvalidArgument(format!("page {} of {} bytes", page, data.len())));
+        }
+        self.pages.insert(page, data.to_vec());
+        Ok(())
+    }
+
+    /* as Intel HEX: 16-byte data records and an EOF */
+    pub fn to_hex(&self) -> String {
+        let mut text = String::new();
+        for (page, data) in self.pages() {
+            let base = page as usize * FLASH_PAGE_SIZE;
+            for (i, chunk) in data.chunks(16).enumerate() {
+                let record = HexRecord::new(RecordType::Data, (base + i * 16) as u16, chunk.to_vec());
+                text.push_str(&record.to_line());
+                text.push('\n');
+            }
+        }
+        text.push_str(&HexRecord::new(RecordType::EndOfFile, 0, vec![]).to_line());
+        text.push('\n');
+        text
+    }
+
+    /* as one flat binary from the first page to the last, 0xFF in the gaps; returns (base, bytes) */
+    pub fn to_binary(&self) -> (u16, Vec<u8>) {
+        let first = match self.pages.keys().next() {
+            Some(page) => *page as usize,
+            None => {
+                return (0, Vec::new());
+            },
+        };
+        let last = self.pages.keys().next_back().map_or(first, |page| *page as usize);
+        let mut bytes = vec![0xffu8; (last - first + 1) * FLASH_PAGE_SIZE];
+        for (page, data) in self.pages() {
+            let offset = (page as usize - first) * FLASH_PAGE_SIZE;
+            bytes[offset..offset + FLASH_PAGE_SIZE].copy_from_slice(data);
+        }
+        ((first * FLASH_PAGE_SIZE) as u16, bytes)
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_record() {
+        let record = HexRecord::parse(":10010000214601360121470136007EFE09D2190140").unwrap();
+        assert_eq!(record.record_type, RecordType::Data);
+        assert_eq!(record.address, 0x0100);
+        assert_eq!(record.data, vec![0x21, 0x46, 0x01, 0x36, 0x01, 0x21, 0x47, 0x01,
+                                     0x36, 0x00, 0x7e, 0xfe, 0x09, 0xd2, 0x19, 0x01]);
+        assert_eq!(record.to_line(), ":10010000214601360121470136007EFE09D2190140");
+        assert_eq!(HexRecord::parse(":00000001FF\r\n").unwrap().record_type, RecordType::EndOfFile);
+    }
+
+    #[test]
+    fn parse_rejects() {
+        // bad checksum, short data, not hex, no colon, unknown type
+        for line in &[":10010000214601360121470136007EFE09D2190141",
+                      ":0F010000214601360121470136007EFE09D2190140",
+                      ":1001000021460136012147013600ZZFE09D2190140",
+                      "10010000214601360121470136007EFE09D2190140",
+                      ":00000030D0"] {
+            assert!(HexRecord::parse(line).is_err(), "{}", line);
+        }
+    }
+
+    #[test]
+    fn bootloader_records() {
+        // what rfcat_bootloader sends for the same commands
+        assert_eq!(HexRecord::new(RecordType::EndOfFile, 0, vec![]).to_line(), ":00000001FF");
+        assert_eq!(HexRecord::new(RecordType::CrcCheck, 0, vec![]).to_line(), ":00000020E0");
+        assert_eq!(HexRecord::new(RecordType::ResetBootloader, 0, vec![]).to_line(), ":00000022DE");
+        assert_eq!(HexRecord::new(RecordType::EraseAll, 0, vec![]).to_line(), ":00000023DD");
+        assert_eq!(HexRecord::new(RecordType::ErasePage, 0, vec![5]).to_line(), ":0100002405D6");
+        assert_eq!(HexRecord::new(RecordType::ReadPage, 0, vec![5]).to_line(), ":0100002505D5");
+        for t in &[0x20, 0x22, 0x23, 0x24, 0x25] {
+            assert_eq!(RecordType::from(*t) as u8, *t);
+        }
+    }
+
+    #[test]
+    fn image_pages() {
+        let text = ":020000040000FA\n\
+                    :0414000002140003CF\n\
+                    \n\
+                    :02180000AA55E7\n\
+                    :00000001FF\n\
+                    :0118000000E7\n";
+        let image = FirmwareImage::parse(text).unwrap();
+        assert_eq!(image.page_count(), 2);
+        let pages: Vec<u16> = image.pages().map(|(page, _)| page).collect();
+        assert_eq!(pages, vec![5, 6]);
+        assert_eq!(image.page(5).unwrap()[..5], [0x02, 0x14, 0x00, 0x03, 0xff]);
+        // the record after EOF doesn't count
+        assert_eq!(image.page(6).unwrap()[..3], [0xaa, 0x55, 0xff]);
+
+        let reparsed = FirmwareImage::parse(&image.to_hex()).unwrap();
+        assert_eq!(reparsed, image);
+        let (base, bytes) = image.to_binary();
+        assert_eq!((base, bytes.len()), (0x1400, 2 * FLASH_PAGE_SIZE));
+        assert_eq!(FirmwareImage::from_binary(base, &bytes).unwrap(), image);
+    }
+
+    #[test]
+    fn image_past_64k() {
+        assert!(FirmwareImage::parse(":020000040001F9\n:0100000000FF\n").is_err());
+        assert!(FirmwareImage::from_binary(0xfff0, &[0u8; 0x20]).is_err());
+    }
+}
@@ -0,0 +1,397 @@
+/*
+ * the radio registers as other tools write them down: TI SmartRF Studio's
+ * register export (#define SMARTRF_SETTING_<NAME> 0x.., or NAME = 0x..; /
+ * NAME |0xADDR|0x..| tables from the other templates), and rfcat's
+ * RadioConfig byte string, the Python bytes literal of the whole
+ * 0xDF00 block that d.getRadioConfig() / d.peek(0xdf00, 0x3e) hands back.
+ */
+
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::profile::{RadioProfile, RegisterBytes};
+use crate::registers;
+use crate::{CCRegisters, RadioConfig, RADIO_CONFIG_LEN, RADIO_CONFIG_WRITABLE_LEN};
+
+const SMARTRF_SETTING_PREFIX: &str = "SMARTRF_SETTING_";
+
+impl RadioConfig {
+
+    /* the writable registers as a SmartRF Studio style header */
+    pub fn to_smartrf(&self) -> String {
+        let base = CCRegisters::Sync1 as u16;
+        let bytes = self.to_bytes();
+        let mut text = String::new();
+        text.push_str("// CC1111 radio registers, SmartRF Studio register export format\n");
+        text.push_str("#ifndef SMARTRF_CC1111_H\n");
+        text.push_str("#define SMARTRF_CC1111_H\n\n");
+        text.push_str("#define SMARTRF_RADIO_CC1111\n");
+        for reg in registers::radio() {
+            let addr = reg.xdata.unwrap();
+            if addr >= base + RADIO_CONFIG_WRITABLE_LEN {
+                continue
+            }
+            let define = format!("{}{}", SMARTRF_SETTING_PREFIX, reg.name);
+            text.push_str(&format!("#define {:<32}0x{:02X}\n", define, bytes[(addr - base) as usize]));
+        }
+        text.push_str("\n#endif\n");
+        text
+    }
+
+    /* the whole register block as a Python bytes literal, the way rfcat prints it */
+    pub fn to_rfcat(&self) -> String {
+        let mut text = String::from("b'");
+        for b in self.to_bytes() {
+            match b {
+                b'\t' => text.push_str("\\t"),
+                b'\n' => text.push_str("\\n"),
+                b'\r' => text.push_str("\\r"),
+                b'\\' => text.push_str("\\\\"),
+                b'\'' => text.push_str("\\'"),
+                0x20..=0x7e => text.push(b as char),
+                _ => text.push_str(&format!("\\x{:02x}", b)),
+            }
+        }
+        text.push('\'');
+        text
+    }
+
+    /*
+     * an rfcat byte string: 'abc\x00..', b'..' or "..", or failing that
+     * plain hex.  anything from the writable part of the block up to all
+     * of it will do; the status registers read back as zero if missing.
+     */
+    pub fn from_rfcat(text: &str) -> Result<RadioConfig, Error> {
+        let text = text.trim();
+        let mut bytes = match text.find(&['\'', '"'][..]) {
+            Some(_) => parse_py_bytes(text)?,
+            None => parse_hex(text)?,
+        };
+        if bytes.len() < RADIO_CONFIG_WRITABLE_LEN as usize || bytes.len() > RADIO_CONFIG_LEN as usize {
+            return Err(Error::InvalidArgument(format!("rfcat radio config is {} bytes, need 0x{:x} to 0x{:x}", bytes.len(), RADIO_CONFIG_WRITABLE_LEN, RADIO_CONFIG_LEN)));
+        }
+        bytes.resize(RADIO_CONFIG_LEN as usize, 0);
+        RadioConfig::from_bytes(&bytes[..])
+    }
+}
+
+impl RadioProfile {
+
+    /*
+     * the writable radio registers out of a SmartRF Studio export; lines
+     * that aren't one (comments, include guards, registers the CC1111
+     * doesn't have or can't write) are skipped
+     */
+    pub fn from_smartrf(text: &str) -> Result<RadioProfile, Error> {
+        let base = CCRegisters::Sync1 as u16;
+        let mut regs = BTreeMap::<u16, u8>::new();
+        for line in text.lines() {
+            let line = strip_c_comments(line);
+            let line = line.trim();
+            let mut words = line.trim_start_matches("#define").split(|c: char| c.is_whitespace() || "|=;,".contains(c)).filter(|w| !w.is_empty());
+            let name = match words.next() {
+                Some(name) => name.trim_start_matches(SMARTRF_SETTING_PREFIX),
+                None => continue,
+            };
+            let reg = match registers::by_name(name) {
+                Some(reg) => reg,
+                None => continue,
+            };
+            let addr = match reg.xdata {
+                Some(addr) if addr >= base && addr < base + RADIO_CONFIG_WRITABLE_LEN => addr,
+                _ => continue,
+            };
+            // tables have the address before the value; it's the first 0x.. that fits a byte
+            match words.filter_map(parse_hex_byte).next() {
+                Some(value) => {
+                    regs.insert(addr, value);
+                },
+                None => {
+                    return Err(Error::InvalidArgument(format!("SmartRF line without a byte value: {:?}", line)));
+                },
+            }
+        }
+        if regs.is_empty() {
+            return Err(Error::InvalidArgument("no CC1111 radio registers in SmartRF export".to_string()));
+        }
+        Ok(RadioProfile{
+            registers: RegisterBytes(regs),
+            ..RadioProfile::default()
+        })
+    }
+
+    /* an rfcat byte string, as a full profile */
+    pub fn from_rfcat(text: &str) -> Result<RadioProfile, Error> {
+        Ok(RadioConfig::from_rfcat(text)?.to_profile())
+    }
+}
+
+fn parse_hex_byte(word: &str) -> Option<u8> {
+    let digits = word.strip_prefix("0x").or_else(|| word.strip_prefix("0X"))?;
+    u8::from_str_radix(digits, 16).ok()
+}
+
+/* drop // comments and one-line /* */ comments */
+fn strip_c_comments(line: &str) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    loop {
+        let line_comment = rest.find("//");
+        let block_comment = rest.find("/*");
+        match (line_comment, block_comment) {
+            (Some(l), Some(b)) if l < b => {
+                out.push_str(&rest[..l]);
+                return out;
+            },
+            (Some(l), None) => {
+                out.push_str(&rest[..l]);
+                return out;
+            },
+            (_, Some(b)) => {
+                out.push_str(&rest[..b]);
+                match rest[b + 2..].find("*/") {
+                    Some(end) => rest = &rest[b + 2 + end + 2..],
+                    None => return out,
+                }
+            },
+            (None, None) => {
+                out.push_str(rest);
+                return out;
+            },
+        }
+    }
+}
+
+/* a Python (2 or 3) bytes / str literal, as repr() writes them */
+fn parse_py_bytes(text: &str) -> Result<Vec<u8>, Error> {
+    let bad = || Error::InvalidArgument(format!("not a Python byte string: {:?}", text));
+    let body = text.trim_start_matches(&['b', 'B'][..]);
+    let quote = match body.chars().next() {
+        Some(q) if q == '\'' || q == '"' => q,
+        _ => return Err(bad()),
+    };
+    if body.len() < 2 || !body.ends_with(quote) {
+        return Err(bad());
+    }
+    let mut bytes = Vec::<u8>::new();
+    let mut chars = body[1..body.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            if !c.is_ascii() {
+                return Err(bad());
+            }
+            bytes.push(c as u8);
+            continue
+        }
+        match chars.next() {
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(b) if hex.len() == 2 => bytes.push(b),
+                    _ => return Err(bad()),
+                }
+            },
+            Some('t') => bytes.push(b'\t'),
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('0') => bytes.push(0),
+            Some('\\') => bytes.push(b'\\'),
+            Some('\'') => bytes.push(b'\''),
+            Some('"') => bytes.push(b'"'),
+            _ => return Err(bad()),
+        }
+    }
+    Ok(bytes)
+}
+
+/* "d3 91 ff ..", "d391ff.." */
+fn parse_hex(text: &str) -> Result<Vec<u8>, Error> {
+    let digits: String = text.split_whitespace().collect();
+    let mut bytes = Vec::<u8>::with_capacity(digits.len() / 2);
+    for pair in digits.as_bytes().chunks(2) {
+        let b = match pair.len() {
+            2 => std::str::from_utf8(pair).ok().and_then(|pair| u8::from_str_radix(pair, 16).ok()),
+            _ => None,
+        };
+        match b {
+            Some(b) => bytes.push(b),
+            None => {
+                return Err(Error::InvalidArgument(format!("not an rfcat byte string or hex: {:?}", text)));
+            },
+        }
+    }
+    if bytes.is_empty() {
+        return Err(Error::InvalidArgument(format!("not an rfcat byte string or hex: {:?}", text)));
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* SmartRF Studio 7, CC1111, 868.3 MHz GFSK, "#define" register export template */
+    const SMARTRF_DEFINES: &str = "\
+/***************************************************************
+ *  SmartRF Studio(tm) Export
+ *
+ *  Radio register settings specifed with C-code
+ *  compatible #define statements.
+ *
+ *  RF device: CC1111
+ *
+ ***************************************************************/
+
+#ifndef SMARTRF_CC1111_H
+#define SMARTRF_CC1111_H
+
+#define SMARTRF_RADIO_CC1111
+#define SMARTRF_SETTING_FSCTRL1    0x06
+#define SMARTRF_SETTING_FSCTRL0    0x00
+#define SMARTRF_SETTING_FREQ2      0x24
+#define SMARTRF_SETTING_FREQ1      0x2D
+#define SMARTRF_SETTING_FREQ0      0xDD
+#define SMARTRF_SETTING_MDMCFG4    0xF5
+#define SMARTRF_SETTING_MDMCFG3    0x83
+#define SMARTRF_SETTING_MDMCFG2    0x13
+#define SMARTRF_SETTING_MDMCFG1    0x22
+#define SMARTRF_SETTING_MDMCFG0    0xF8
+#define SMARTRF_SETTING_CHANNR     0x00
+#define SMARTRF_SETTING_DEVIATN    0x15
+#define SMARTRF_SETTING_FREND1     0x56
+#define SMARTRF_SETTING_FREND0     0x10
+#define SMARTRF_SETTING_MCSM0      0x14
+#define SMARTRF_SETTING_FOCCFG     0x16
+#define SMARTRF_SETTING_BSCFG      0x6C
+#define SMARTRF_SETTING_AGCCTRL2   0x03
+#define SMARTRF_SETTING_AGCCTRL1   0x40
+#define SMARTRF_SETTING_AGCCTRL0   0x91
+#define SMARTRF_SETTING_FSCAL3     0xE9
+#define SMARTRF_SETTING_FSCAL2     0x2A
+#define SMARTRF_SETTING_FSCAL1     0x00
+#define SMARTRF_SETTING_FSCAL0     0x1F
+#define SMARTRF_SETTING_TEST2      0x88
+#define SMARTRF_SETTING_TEST1      0x31
+#define SMARTRF_SETTING_TEST0      0x09
+#define SMARTRF_SETTING_PA_TABLE0  0x50
+#define SMARTRF_SETTING_PKTCTRL1   0x04
+#define SMARTRF_SETTING_PKTCTRL0   0x45
+#define SMARTRF_SETTING_ADDR       0x00
+#define SMARTRF_SETTING_PKTLEN     0xFF
+
+#endif
+";
+
+    /* the same settings from the C assignment template */
+    const SMARTRF_ASSIGNMENTS: &str = "\
+// Address config = No address check
+// Base frequency = 868.299683
+// Modulation format = GFSK
+FSCTRL1    = 0x06; // Frequency Synthesizer Control
+FSCTRL0    = 0x00; // Frequency Synthesizer Control
+FREQ2      = 0x24; // Frequency Control Word, High Byte
+FREQ1      = 0x2D; // Frequency Control Word, Middle Byte
+FREQ0      = 0xDD; // Frequency Control Word, Low Byte
+MDMCFG4    = 0xF5; // Modem configuration
+MDMCFG3    = 0x83; // Modem Configuration
+MDMCFG2    = 0x13; // Modem Configuration
+DEVIATN    = 0x15; // Modem Deviation Setting
+FREND0     = 0x10; // Front End TX Configuration
+PA_TABLE0  = 0x50; // PA Power Setting 0
+PKTCTRL0   = 0x45; // Packet Automation Control
+";
+
+    /* and from the register table template: name, address, value, description */
+    const SMARTRF_TABLE: &str = "\
+FSCTRL1    |0xDF07|0x06|Frequency Synthesizer Control
+FSCTRL0    |0xDF08|0x00|Frequency Synthesizer Control
+FREQ2      |0xDF09|0x24|Frequency Control Word, High Byte
+FREQ1      |0xDF0A|0x2D|Frequency Control Word, Middle Byte
+FREQ0      |0xDF0B|0xDD|Frequency Control Word, Low Byte
+MDMCFG4    |0xDF0C|0xF5|Modem configuration
+MDMCFG3    |0xDF0D|0x83|Modem Configuration
+MDMCFG2    |0xDF0E|0x13|Modem Configuration
+DEVIATN    |0xDF11|0x15|Modem Deviation Setting
+FREND0     |0xDF1B|0x10|Front End TX Configuration
+PA_TABLE0  |0xDF2E|0x50|PA Power Setting 0
+PKTCTRL0   |0xDF04|0x45|Packet Automation Control
+MARCSTATE  |0xDF3B|0x01|Main Radio Control State Machine State
+";
+
+    /* rfcat's default 902 MHz config, repr(d.getRadioConfig()) under Python 2 */
+    const RFCAT_REPR: &str = r"'\x0cN\xff@\x05\x00\x00\x06\x00%\x95U\xca\xa3\x03#\x116\x070\x18\x17l\x03@\x91V\x10\xe9*\x00\x1fY\x7f?\x881\t\x00\x00\x00\x00\x00\x00\x00\x00\xc0\x00\x00\x00\x00\x00\x00\x00\x11\x03\x00\x00\x80\x01\x00\x94'";
+    const RFCAT_HEX: &str = "0c 4e ff 40 05 00 00 06 00 25 95 55 ca a3 03 23 11 36 07 30 18 17 6c 03 40 91 56 10 e9 2a 00 1f
+                             59 7f 3f 88 31 09 00 00 00 00 00 00 00 00 c0 00 00 00 00 00 00 00 11 03 00 00 80 01 00 94";
+
+    fn reg(profile: &RadioProfile, name: &str) -> Option<u8> {
+        let addr = registers::by_name(name).unwrap().xdata.unwrap();
+        profile.registers.0.get(&addr).cloned()
+    }
+
+    #[test]
+    fn smartrf_layouts() {
+        let defines = RadioProfile::from_smartrf(SMARTRF_DEFINES).unwrap();
+        assert_eq!(defines.registers.0.len(), 32);
+        assert_eq!((reg(&defines, "FREQ2"), reg(&defines, "PKTLEN"), reg(&defines, "PA_TABLE0")), (Some(0x24), Some(0xff), Some(0x50)));
+        assert_eq!(reg(&defines, "SYNC1"), None);
+
+        let assignments = RadioProfile::from_smartrf(SMARTRF_ASSIGNMENTS).unwrap();
+        let table = RadioProfile::from_smartrf(SMARTRF_TABLE).unwrap();
+        // the table's address column isn't taken for the value, and MARCSTATE is read-only
+        assert_eq!(table, assignments);
+        assert_eq!(assignments.registers.0.len(), 12);
+        for (addr, value) in assignments.registers.0.iter() {
+            assert_eq!(defines.registers.0.get(addr), Some(value));
+        }
+
+        let mut config = RadioConfig::default();
+        config.apply_profile(&defines).unwrap();
+        assert!((config.frequency() as i64 - 868_300_000).abs() < 400);
+        assert_eq!(config.modulation(), crate::ModulationFormat::Gfsk);
+
+        assert!(RadioProfile::from_smartrf("#define SMARTRF_RADIO_CC1111\n").is_err());
+        assert!(RadioProfile::from_smartrf("#define SMARTRF_SETTING_FREQ2 36\n").is_err());
+    }
+
+    #[test]
+    fn rfcat_repr_and_hex() {
+        let config = RadioConfig::from_rfcat(RFCAT_REPR).unwrap();
+        assert_eq!(config.sync_word(), 0x0c4e);
+        assert_eq!((config.freq2, config.freq1, config.freq0), (0x25, 0x95, 0x55));
+        assert_eq!((config.pa_table0, config.partnum, config.vco_vc_dac), (0xc0, 0x11, 0x94));
+        assert_eq!(RadioConfig::from_rfcat(&format!("b{}", RFCAT_REPR)).unwrap(), config);
+        assert_eq!(RadioConfig::from_rfcat(RFCAT_HEX).unwrap(), config);
+        assert_eq!(RadioConfig::from_rfcat(&RFCAT_HEX.replace(char::is_whitespace, "")).unwrap(), config);
+
+        // just the writable part; the status registers come back zero
+        let short = RadioConfig::from_rfcat(&RFCAT_HEX[..RFCAT_HEX.find("11 03").unwrap()]).unwrap();
+        assert_eq!(short.to_bytes()[..RADIO_CONFIG_WRITABLE_LEN as usize], config.to_bytes()[..RADIO_CONFIG_WRITABLE_LEN as usize]);
+        assert_eq!(short.partnum, 0);
+
+        assert!(RadioConfig::from_rfcat(r"'\x0cN\xff'").is_err());
+        assert!(RadioConfig::from_rfcat(r"'\x0cN\xzz'").is_err());
+        assert!(RadioConfig::from_rfcat("0c 4e f").is_err());
+        assert!(RadioConfig::from_rfcat(&format!("{} 00", RFCAT_HEX)).is_err());
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut config = RadioConfig::from_rfcat(RFCAT_REPR).unwrap();
+        assert_eq!(config.to_rfcat(), format!("b{}", RFCAT_REPR));
+        // a spread of byte values, \t among them
+        let bytes: Vec<u8> = (0..RADIO_CONFIG_LEN).map(|i| (i as u8).wrapping_mul(37).wrapping_add(9)).collect();
+        let odd = RadioConfig::from_bytes(&bytes).unwrap();
+        assert_eq!(RadioConfig::from_rfcat(&odd.to_rfcat()).unwrap(), odd);
+
+        config.sync1 = b'\'';
+        config.sync0 = b'\\';
+        assert_eq!(RadioConfig::from_rfcat(&config.to_rfcat()).unwrap(), config);
+
+        let profile = RadioProfile::from_smartrf(&config.to_smartrf()).unwrap();
+        assert_eq!(profile.registers, config.to_profile().registers);
+        let mut loaded = RadioConfig::default();
+        loaded.apply_profile(&profile).unwrap();
+        // everything with a name; the reserved 0xDF20 - 0xDF22 have none to export under
+        assert_eq!(loaded.to_profile(), config.to_profile());
+    }
+}
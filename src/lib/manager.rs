@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::transport::TransportIdentity;
+use crate::usb::{shared_context, UsbTransport};
+use crate::{is_rfcat_bootloader, is_standard_rfcat, OwnedRFCatDevice, RFCatDevice, RadioConfig};
+
+// libusb 0.3 has no hotplug callbacks, so we rescan the bus this often
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum DeviceKind {
+    RfCat,
+    Bootloader,
+}
+
+#[derive(Debug)]
+pub enum DeviceEvent {
+    // showed up for the first time (or without a serial number we could match)
+    Arrived {
+        kind: DeviceKind,
+        identity: TransportIdentity,
+        serial_number: Option<String>,
+    },
+    // came back under the same serial number and got its last radio config restored
+    Reconnected {
+        identity: TransportIdentity,
+        serial_number: String,
+    },
+    // gone from the bus
+    Removed {
+        kind: DeviceKind,
+        identity: TransportIdentity,
+        serial_number: Option<String>,
+    },
+    // on the bus, but we couldn't open or restore it
+    Failed {
+        identity: TransportIdentity,
+        error: Error,
+    },
+}
+
+struct Present {
+    kind: DeviceKind,
+    identity: TransportIdentity,
+    serial_number: Option<String>,
+}
+
+/*
+ * keeps track of RFCats (and RFCats in their bootloader) coming and going.
+ * RFCats get opened as they arrive; when one disappears its last radio config
+ * is remembered, and put back if the same serial number shows up again.
+ */
+pub struct DeviceManager {
+    context: &'static libusb::Context,
+    // everything matching we saw on the last scan, by (bus, address)
+    present: HashMap<(u8, u8), Present>,
+    // open RFCats, by (bus, address)
+    devices: HashMap<(u8, u8), OwnedRFCatDevice>,
+    // last radio config of every RFCat that went away, by serial number
+    saved_configs: HashMap<String, RadioConfig>,
+}
+
+impl DeviceManager {
+
+    pub fn new() -> Result<DeviceManager, Error> {
+        Ok(DeviceManager{
+            context: shared_context()?,
+            present: HashMap::new(),
+            devices: HashMap::new(),
+            saved_configs: HashMap::new(),
+        })
+    }
+
+    /* rescan the bus once, returning what changed since the last scan */
+    pub fn poll(&mut self) -> Result<Vec<DeviceEvent>, Error> {
+        let mut events = Vec::<DeviceEvent>::new();
+        let mut seen = Vec::<(u8, u8)>::new();
+        for device in self.context.devices()?.iter() {
+            let device_desc = match device.device_descriptor() {
+                Ok(k) => k,
+                Err(_) => continue,
+            };
+            let kind = if is_standard_rfcat(&device_desc) {
+                DeviceKind::RfCat
+            } else if is_rfcat_bootloader(&device_desc) {
+                DeviceKind::Bootloader
+            } else {
+                continue
+            };
+            let key = (device.bus_number(), device.address());
+            seen.push(key);
+            if self.present.contains_key(&key) {
+                continue
+            }
+            let identity = TransportIdentity{
+                bus_number: key.0,
+                address: key.1,
+                vendor_id: device_desc.vendor_id(),
+                product_id: device_desc.product_id(),
+            };
+            match kind {
+                // the bootloader speaks its own protocol: just report it
+                DeviceKind::Bootloader => {
+                    self.present.insert(key, Present{kind: kind, identity: identity, serial_number: None});
+                    events.push(DeviceEvent::Arrived{kind: kind, identity: identity, serial_number: None});
+                },
+                DeviceKind::RfCat => {
+                    match self.open(device, device_desc) {
+                        Ok((rfcat, serial_number, event)) => {
+                            self.devices.insert(key, rfcat);
+                            self.present.insert(key, Present{kind: kind, identity: identity, serial_number: serial_number});
+                            events.push(event);
+                        },
+                        // leave it out of present so the next scan gives it another go
+                        Err(err) => events.push(DeviceEvent::Failed{identity: identity, error: err}),
+                    }
+                },
+            }
+        }
+
+        let gone: Vec<(u8, u8)> = self.present.keys().filter(|key| !seen.contains(key)).cloned().collect();
+        for key in gone {
+            let present = match self.present.remove(&key) {
+                Some(present) => present,
+                None => continue,
+            };
+            if let Some(rfcat) = self.devices.remove(&key) {
+                if let (Some(serial_number), Some(config)) = (&present.serial_number, rfcat.last_radio_config()) {
+                    self.saved_configs.insert(serial_number.clone(), config);
+                }
+            }
+            events.push(DeviceEvent::Removed{
+                kind: present.kind,
+                identity: present.identity,
+                serial_number: present.serial_number,
+            });
+        }
+        Ok(events)
+    }
+
+    /* poll forever, handing every event to f; stops (with the error) if a scan fails */
+    pub fn watch<F>(&mut self, interval: Duration, mut f: F) -> Result<(), Error>
+        where F: FnMut(&DeviceManager, DeviceEvent) {
+        loop {
+            for event in self.poll()? {
+                f(self, event);
+            }
+            thread::sleep(interval);
+        }
+    }
+
+    fn open(
+        &mut self,
+        device: libusb::Device<'static>,
+        device_desc: libusb::DeviceDescriptor,
+    ) -> Result<(OwnedRFCatDevice, Option<String>, DeviceEvent), Error> {
+        let rfcat = RFCatDevice::from_transport(Box::new(UsbTransport::open(device, device_desc)?));
+        let identity = rfcat.transport().identity();
+        let serial_number = match rfcat.serial_number() {
+            Ok(serial_number) => serial_number,
+            Err(_) => {
+                return Ok((rfcat, None, DeviceEvent::Arrived{kind: DeviceKind::RfCat, identity: identity, serial_number: None}));
+            },
+        };
+        match self.saved_configs.get(&serial_number) {
+            Some(config) => {
+                rfcat.set_radio_config(config)?;
+                self.saved_configs.remove(&serial_number);
+                Ok((rfcat, Some(serial_number.clone()), DeviceEvent::Reconnected{identity: identity, serial_number: serial_number}))
+            },
+            None => Ok((rfcat, Some(serial_number.clone()), DeviceEvent::Arrived{
+                kind: DeviceKind::RfCat,
+                identity: identity,
+                serial_number: Some(serial_number),
+            })),
+        }
+    }
+
+    /* the open RFCat with this serial number, if it's plugged in */
+    pub fn device(&self, serial_number: &str) -> Option<&OwnedRFCatDevice> {
+        let key = self.present.iter()
+            .find(|(_, present)| present.serial_number.as_deref() == Some(serial_number))
+            .map(|(key, _)| *key)?;
+        self.devices.get(&key)
+    }
+
+    /* the open RFCat at bus / address, if there is one */
+    pub fn device_at(&self, bus_number: u8, address: u8) -> Option<&OwnedRFCatDevice> {
+        self.devices.get(&(bus_number, address))
+    }
+
+    pub fn devices(&self) -> impl Iterator<Item = &OwnedRFCatDevice> {
+        self.devices.values()
+    }
+
+    /* remember a radio config for serial_number, to be put back when it (re)appears */
+    pub fn save_config(&mut self, serial_number: &str, config: RadioConfig) {
+        self.saved_configs.insert(serial_number.to_string(), config);
+    }
+}
@@ -0,0 +1,635 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::transport::{Transport, TransportIdentity};
+use crate::{AppMailbox, MainRadioControlState, NicCommand, NicReturnCode, RfState, SystemCommand};
+
+/* CC1111 radio register block (0xDF00 - 0xDF3D) as it comes out of reset */
+pub(crate) const RADIO_DEFAULTS: [u8; 0x3e] = [
+    0xd3, 0x91, 0xff, 0x04, 0x45, 0x00, 0x00, 0x0f, // SYNC1 .. FSCTRL1
+    0x00, 0x1e, 0xc4, 0xec, 0x8c, 0x22, 0x02, 0x22, // FSCTRL0 .. MDMCFG1
+    0xf8, 0x47, 0x07, 0x30, 0x04, 0x36, 0x6c, 0x03, // MDMCFG0 .. AGCCTRL2
+    0x40, 0x91, 0x56, 0x10, 0xa9, 0x0a, 0x20, 0x0d, // AGCCTRL1 .. FSCAL0
+    0x00, 0x00, 0x00, 0x88, 0x31, 0x0b, 0x00, 0x00, // Z0 .. PA_TABLE7
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // PA_TABLE6 .. IOCFG2
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x03, // IOCFG1 .. CHIPID
+    0x00, 0x00, 0x80, 0x01, 0x00, 0x00,             // FREQEST .. VCO_VC_DAC
+];
+
+const RADIO_BASE: u16 = 0xdf00;
+const BOOTLOADER_MAGIC_ADDR: u16 = 0xdf46;
+const RFST_ADDR: u16 = 0xdfe1;
+const MARCSTATE_ADDR: u16 = 0xdf3b;
+
+/* the emulated CC: XDATA plus whatever it still owes the host */
+struct MockCC {
+    xdata: Vec<u8>,
+    build_name: Option<String>,
+    compiler: Option<String>,
+    max_packet_size: usize,
+    // transfers the CC has queued up for the host's next bulk reads
+    outbound: VecDeque<Vec<u8>>,
+    // every frame the host sent, in order
+    sent: Vec<Vec<u8>>,
+    // what went out over the air, one entry per XMIT / LONG_XMIT
+    transmitted: Vec<Vec<u8>>,
+    long_xmit: Option<Vec<u8>>,
+    // answer this many LONG_XMIT_MORE chunks with "buffer not available" first
+    busy_chunks: u32,
+    amp_mode: u8,
+    aes_mode: u8,
+    // the state machine ignores strobes (a wedged radio)
+    stuck: bool,
+    // went off to the bootloader, or was reset: the wire is dead
+    detached: bool,
+}
+
+impl MockCC {
+    fn reply(&mut self, mbx: u8, cmd: u8, payload: &[u8]) {
+        let plen = (payload.len() as u16).to_le_bytes();
+        let mut frame = vec![0x40, mbx, cmd, plen[0], plen[1]];
+        frame.extend_from_slice(payload);
+        self.outbound.push_back(frame);
+    }
+
+    fn peek(&self, addr: u16, len: u16) -> Vec<u8> {
+        (0..len).map(|off| self.xdata[addr.wrapping_add(off) as usize]).collect()
+    }
+
+    fn poke(&mut self, addr: u16, data: &[u8]) {
+        for (off, b) in data.iter().enumerate() {
+            let a = addr.wrapping_add(off as u16);
+            self.xdata[a as usize] = *b;
+            if a == RFST_ADDR {
+                self.strobe(RfState::from(*b));
+            }
+        }
+    }
+
+    /* a write to RFST: the state machine lands wherever the strobe goes, instantly */
+    fn strobe(&mut self, strobe: RfState) {
+        if self.stuck {
+            return;
+        }
+        let marcstate = match strobe {
+            RfState::SIDLE | RfState::SCAL => MainRadioControlState::Idle,
+            RfState::SRX => MainRadioControlState::Rx,
+            RfState::STX => MainRadioControlState::Tx,
+            RfState::SFSTXON => MainRadioControlState::FstXOn,
+            _ => return,
+        };
+        self.xdata[MARCSTATE_ADDR as usize] = marcstate as u8;
+    }
+
+    fn handle_system(&mut self, cmd: u8, payload: &[u8]) {
+        let mbx = AppMailbox::AppSystem as u8;
+        match SystemCommand::from(cmd) {
+            SystemCommand::Ping | SystemCommand::LedMode => {
+                self.reply(mbx, cmd, payload);
+            },
+            // the firmware strobes the new mode as well as remembering it
+            SystemCommand::RFMode => {
+                if let Some(mode) = payload.first() {
+                    self.strobe(RfState::from(*mode));
+                }
+                self.reply(mbx, cmd, payload);
+            },
+            SystemCommand::BuildType => {
+                let name = self.build_name.clone().unwrap_or_default();
+                self.reply(mbx, cmd, name.as_bytes());
+            },
+            SystemCommand::Compiler => {
+                let name = self.compiler.clone().unwrap_or_default();
+                self.reply(mbx, cmd, name.as_bytes());
+            },
+            SystemCommand::PartNum => {
+                let partnum = self.xdata[0xdf36];
+                self.reply(mbx, cmd, &[partnum]);
+            },
+            SystemCommand::Peek => {
+                if payload.len() < 4 {
+                    return;
+                }
+                let len = u16::from_le_bytes([payload[0], payload[1]]);
+                let addr = u16::from_le_bytes([payload[2], payload[3]]);
+                let data = self.peek(addr, len);
+                self.reply(mbx, cmd, &data);
+            },
+            SystemCommand::Poke | SystemCommand::PokeRegister => {
+                if payload.len() < 2 {
+                    return;
+                }
+                let addr = u16::from_le_bytes([payload[0], payload[1]]);
+                let data = &payload[2..];
+                self.poke(addr, data);
+                self.reply(mbx, cmd, &(data.len() as u16).to_le_bytes());
+            },
+            SystemCommand::Bootloader | SystemCommand::Reset => {
+                self.reply(mbx, cmd, &[]);
+                self.detached = true;
+            },
+            _ => (),
+        }
+    }
+
+    fn handle_nic(&mut self, cmd: u8, payload: &[u8]) {
+        let mbx = AppMailbox::AppNic as u8;
+        match NicCommand::from(cmd) {
+            NicCommand::Xmit => {
+                if payload.len() < 6 {
+                    self.reply(mbx, cmd, &[NicReturnCode::ErrBufferSizeExceeded as u8]);
+                    return;
+                }
+                let len = u16::from_le_bytes([payload[0], payload[1]]) as usize;
+                let repeat = u16::from_le_bytes([payload[2], payload[3]]) as usize;
+                let offset = u16::from_le_bytes([payload[4], payload[5]]) as usize;
+                let data = &payload[6..];
+                if data.len() != len || offset >= std::cmp::max(len, 1) {
+                    self.reply(mbx, cmd, &[NicReturnCode::ErrBufferSizeExceeded as u8]);
+                    return;
+                }
+                let mut air = data.to_vec();
+                for _ in 0..repeat {
+                    air.extend_from_slice(&data[offset..]);
+                }
+                self.transmitted.push(air);
+                self.reply(mbx, cmd, &[NicReturnCode::NoError as u8]);
+            },
+            NicCommand::LongXmit => {
+                if payload.len() < 3 {
+                    self.reply(mbx, cmd, &[NicReturnCode::ErrBufferSizeExceeded as u8]);
+                    return;
+                }
+                self.long_xmit = Some(payload[3..].to_vec());
+                self.reply(mbx, cmd, &[NicReturnCode::NoError as u8]);
+            },
+            NicCommand::LongXmitMore => {
+                if self.busy_chunks > 0 {
+                    self.busy_chunks -= 1;
+                    self.reply(mbx, cmd, &[NicReturnCode::TempErrBufferNotAvailable as u8]);
+                    return;
+                }
+                let chunk_len = payload.first().cloned().unwrap_or(0) as usize;
+                let chunk = &payload[1..std::cmp::min(1 + chunk_len, payload.len())];
+                let code = match self.long_xmit.take() {
+                    Some(mut buf) => {
+                        if chunk_len == 0 {
+                            self.transmitted.push(buf);
+                        } else {
+                            buf.extend_from_slice(chunk);
+                            self.long_xmit = Some(buf);
+                        }
+                        NicReturnCode::NoError
+                    },
+                    None => NicReturnCode::TxError,
+                };
+                self.reply(mbx, cmd, &[code as u8]);
+            },
+            NicCommand::SetAmpMode => {
+                self.amp_mode = payload.first().cloned().unwrap_or(0);
+                self.reply(mbx, cmd, &[]);
+            },
+            NicCommand::GetAmpMode => {
+                let amp_mode = self.amp_mode;
+                self.reply(mbx, cmd, &[amp_mode]);
+            },
+            NicCommand::SetAesMode => {
+                self.aes_mode = payload.first().cloned().unwrap_or(0);
+                self.reply(mbx, cmd, &[]);
+            },
+            NicCommand::GetAesMode => {
+                let aes_mode = self.aes_mode;
+                self.reply(mbx, cmd, &[aes_mode]);
+            },
+            NicCommand::SetId | NicCommand::SetRecvLarge | NicCommand::SetAesIv | NicCommand::SetAesKey => {
+                self.reply(mbx, cmd, &[]);
+            },
+            _ => (),
+        }
+    }
+
+    fn handle_frame(&mut self, frame: &[u8]) {
+        if frame.len() < 4 {
+            return;
+        }
+        let mbx = frame[0];
+        let cmd = frame[1];
+        let plen = u16::from_le_bytes([frame[2], frame[3]]) as usize;
+        let payload_end = std::cmp::min(4 + plen, frame.len());
+        let payload = frame[4..payload_end].to_vec();
+        match AppMailbox::from(mbx) {
+            AppMailbox::AppSystem => self.handle_system(cmd, &payload),
+            AppMailbox::AppNic => self.handle_nic(cmd, &payload),
+            _ => (),
+        }
+    }
+}
+
+/*
+ * an in-process, emulated CC1111 speaking the RfCat protocol, for poking at
+ * the CC layer without a dongle plugged in.  clones share the same CC, so
+ * keep one around to look at (or fiddle with) its memory after handing the
+ * other to RFCatDevice::from_transport.
+ */
+#[derive(Clone)]
+pub struct MockTransport {
+    cc: Arc<Mutex<MockCC>>,
+    identity: TransportIdentity,
+    serial_number: Option<String>,
+    timeout: Duration,
+}
+
+impl Default for MockTransport {
+    fn default() -> MockTransport {
+        MockTransport::new()
+    }
+}
+
+impl MockTransport {
+
+    /* a YARD Stick One lookalike with CC-Bootloader */
+    pub fn new() -> MockTransport {
+        let mut xdata = vec![0u8; 0x10000];
+        let base = RADIO_BASE as usize;
+        xdata[base..base + RADIO_DEFAULTS.len()].copy_from_slice(&RADIO_DEFAULTS);
+        let mut cc = MockCC{
+            xdata: xdata,
+            build_name: Some("YARDSTICKONE r0543".to_string()),
+            compiler: Some("SDCCv370".to_string()),
+            max_packet_size: 64,
+            outbound: VecDeque::new(),
+            sent: Vec::new(),
+            transmitted: Vec::new(),
+            long_xmit: None,
+            busy_chunks: 0,
+            amp_mode: 0,
+            aes_mode: 0,
+            stuck: false,
+            detached: false,
+        };
+        cc.poke(BOOTLOADER_MAGIC_ADDR, &[0xf0, 0x0d]);
+        MockTransport{
+            cc: Arc::new(Mutex::new(cc)),
+            identity: TransportIdentity{
+                bus_number: 0,
+                address: 0,
+                vendor_id: 0x1d50,
+                product_id: 0x605b,
+            },
+            serial_number: None,
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    pub fn with_identity(mut self, identity: TransportIdentity) -> MockTransport {
+        self.identity = identity;
+        self
+    }
+
+    pub fn with_serial_number(mut self, serial_number: &str) -> MockTransport {
+        self.serial_number = Some(serial_number.to_string());
+        self
+    }
+
+    pub fn with_build_name(self, build_name: Option<&str>) -> MockTransport {
+        self.cc.lock().unwrap().build_name = build_name.map(|s| s.to_string());
+        self
+    }
+
+    pub fn with_compiler(self, compiler: Option<&str>) -> MockTransport {
+        self.cc.lock().unwrap().compiler = compiler.map(|s| s.to_string());
+        self
+    }
+
+    pub fn with_bootloader(self, bootloader: bool) -> MockTransport {
+        let magic = if bootloader { [0xf0, 0x0d] } else { [0x00, 0x00] };
+        self.cc.lock().unwrap().poke(BOOTLOADER_MAGIC_ADDR, &magic);
+        self
+    }
+
+    /* how many bytes the CC hands back per bulk read */
+    pub fn with_max_packet_size(self, max_packet_size: usize) -> MockTransport {
+        self.cc.lock().unwrap().max_packet_size = max_packet_size;
+        self
+    }
+
+    /* read emulated XDATA directly, without going through the protocol */
+    pub fn memory(&self, addr: u16, len: u16) -> Vec<u8> {
+        self.cc.lock().unwrap().peek(addr, len)
+    }
+
+    /* write emulated XDATA directly, without going through the protocol */
+    pub fn set_memory(&self, addr: u16, data: &[u8]) {
+        self.cc.lock().unwrap().poke(addr, data);
+    }
+
+    /* queue raw bytes for the host, e.g. garbage or unsolicited frames */
+    pub fn inject(&self, bytes: &[u8]) {
+        self.cc.lock().unwrap().outbound.push_back(bytes.to_vec());
+    }
+
+    /* have the radio "receive" a packet, handed up as an unsolicited NIC RECV */
+    pub fn inject_rf(&self, data: &[u8]) {
+        self.cc.lock().unwrap().reply(AppMailbox::AppNic as u8, NicCommand::Recv as u8, data);
+    }
+
+    /* everything the radio has "sent" over the air so far */
+    pub fn transmitted(&self) -> Vec<Vec<u8>> {
+        self.cc.lock().unwrap().transmitted.clone()
+    }
+
+    /* answer the next n LONG_XMIT_MORE chunks with "buffer not available" */
+    pub fn set_busy_chunks(&self, n: u32) {
+        self.cc.lock().unwrap().busy_chunks = n;
+    }
+
+    /* have the radio ignore strobes, and stay in whatever state it's in */
+    pub fn set_stuck(&self, stuck: bool) {
+        self.cc.lock().unwrap().stuck = stuck;
+    }
+
+    /* every frame the host has sent so far */
+    pub fn sent(&self) -> Vec<Vec<u8>> {
+        self.cc.lock().unwrap().sent.clone()
+    }
+
+    /* the values written to RFST so far, oldest first */
+    pub fn strobes(&self) -> Vec<u8> {
+        self.sent().iter()
+            .filter(|f| f[1] == SystemCommand::Poke as u8 && f[4..6] == RFST_ADDR.to_le_bytes())
+            .flat_map(|f| f[6..].to_vec())
+            .collect()
+    }
+
+    /* has the CC dropped off the bus (bootloader / reset)? */
+    pub fn detached(&self) -> bool {
+        self.cc.lock().unwrap().detached
+    }
+}
+
+impl Transport for MockTransport {
+
+    fn send_frame(&self, frame: &[u8]) -> Result<usize, Error> {
+        let mut cc = self.cc.lock().unwrap();
+        if cc.detached {
+            return Err(Error::Disconnected);
+        }
+        cc.sent.push(frame.to_vec());
+        cc.handle_frame(frame);
+        Ok(frame.len())
+    }
+
+    fn recv_frame(&self) -> Result<Vec<u8>, Error> {
+        let mut cc = self.cc.lock().unwrap();
+        let max_packet_size = cc.max_packet_size;
+        let transfer = match cc.outbound.front_mut() {
+            Some(transfer) => transfer,
+            None => {
+                if cc.detached {
+                    return Err(Error::Disconnected);
+                }
+                return Err(Error::Timeout);
+            },
+        };
+        // a read never spans two transfers, and never returns more than a buffer's worth
+        let take = std::cmp::min(max_packet_size, transfer.len());
+        let chunk: Vec<u8> = transfer.drain(..take).collect();
+        if transfer.is_empty() {
+            cc.outbound.pop_front();
+        }
+        Ok(chunk)
+    }
+
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    fn identity(&self) -> TransportIdentity {
+        self.identity
+    }
+
+    fn manufacturer(&self) -> Result<String, Error> {
+        Ok("Great Scott Gadgets".to_string())
+    }
+
+    fn product(&self) -> Result<String, Error> {
+        Ok("YARD Stick One".to_string())
+    }
+
+    fn serial_number(&self) -> Result<String, Error> {
+        match &self.serial_number {
+            Some(serial_number) => Ok(serial_number.clone()),
+            None => Err(Error::Unsupported("descriptor strings")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RFCatDevice;
+
+    fn open(mock: &MockTransport) -> RFCatDevice<'static> {
+        RFCatDevice::from_transport(Box::new(mock.clone()))
+    }
+
+    #[test]
+    fn ping() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        assert!(rfcat.ping().unwrap());
+        assert_eq!(mock.sent(), vec![vec![0xff, SystemCommand::Ping as u8, 0x00, 0x00]]);
+    }
+
+    #[test]
+    fn buildname_and_compiler() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        assert_eq!(rfcat.buildname().unwrap(), Some("YARDSTICKONE r0543".to_string()));
+        assert_eq!(rfcat.compiler().unwrap(), Some("SDCCv370".to_string()));
+
+        let mock = MockTransport::new().with_build_name(None).with_compiler(None);
+        let rfcat = open(&mock);
+        assert_eq!(rfcat.buildname().unwrap(), None);
+        assert_eq!(rfcat.compiler().unwrap(), None);
+    }
+
+    #[test]
+    fn peek_radio_block() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        assert_eq!(rfcat.peek(RADIO_BASE, 0x3e).unwrap(), RADIO_DEFAULTS.to_vec());
+        // le16 length, then le16 address
+        assert_eq!(mock.sent()[0], vec![0xff, SystemCommand::Peek as u8, 0x04, 0x00, 0x3e, 0x00, 0x00, 0xdf]);
+    }
+
+    #[test]
+    fn peek_in_small_reads() {
+        let mock = MockTransport::new().with_max_packet_size(7);
+        let rfcat = open(&mock);
+        assert_eq!(rfcat.peek(RADIO_BASE, 0x3e).unwrap(), RADIO_DEFAULTS.to_vec());
+    }
+
+    #[test]
+    fn poke_radio_block() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        rfcat.poke(0xdf09, &[0x21, 0x62, 0x76]).unwrap();
+        assert_eq!(mock.memory(0xdf09, 3), vec![0x21, 0x62, 0x76]);
+        assert_eq!(rfcat.peek(0xdf00, 0x3e).unwrap()[0x09..0x0c], [0x21, 0x62, 0x76]);
+        rfcat.poke_reg_verified(0xdf00, &[0x0b, 0xad]).unwrap();
+        assert_eq!(mock.memory(0xdf00, 2), vec![0x0b, 0xad]);
+    }
+
+    #[test]
+    fn poke_short_reply() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        // a POKE answer with only half a written-count in it, ahead of the real one
+        mock.inject(&[0x40, 0xff, SystemCommand::Poke as u8, 0x01, 0x00, 0x02]);
+        match rfcat.poke(0xdf00, &[0x12, 0x34]) {
+            Err(Error::UnexpectedReply{..}) => (),
+            other => panic!("poke with a short reply: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn transmit() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        rfcat.transmit(&[1, 2, 3], 2, 1).unwrap();
+        assert_eq!(mock.transmitted(), vec![vec![1, 2, 3, 2, 3, 2, 3]]);
+    }
+
+    #[test]
+    fn transmit_empty_reply() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        mock.inject(&[0x40, AppMailbox::AppNic as u8, NicCommand::Xmit as u8, 0x00, 0x00]);
+        match rfcat.transmit(&[1, 2, 3], 0, 0) {
+            Err(Error::UnexpectedReply{..}) => (),
+            other => panic!("transmit with an empty reply: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn transmit_long_waits_for_buffers() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        let data: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        mock.set_busy_chunks(3);
+        rfcat.transmit_long(&data).unwrap();
+        assert_eq!(mock.transmitted(), vec![data]);
+        let more = mock.sent().iter().filter(|f| f[1] == NicCommand::LongXmitMore as u8).count();
+        // 4 chunks after the preload, the empty one to finish, and the 3 refused
+        assert_eq!(more, 4 + 1 + 3);
+    }
+
+    #[test]
+    fn unknown_mailbox_dropped() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        mock.inject(&[0x40, 0x43, 0x01, 0x02, 0x00, 0x40, 0xff]);
+        assert!(rfcat.ping().unwrap());
+        assert_eq!(rfcat.queued(AppMailbox::UNKNOWN, 0x01), 0);
+    }
+
+    #[test]
+    fn with_idle_radio_wont_idle() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        mock.set_memory(MARCSTATE_ADDR, &[MainRadioControlState::Rx as u8]);
+        mock.set_stuck(true);
+        match rfcat.with_idle(|_| Ok(())) {
+            Err(Error::Timeout) => (),
+            other => panic!("with_idle on a wedged radio: {:?}", other),
+        }
+        // SIDLE, then the attempt to put it back into RX
+        assert_eq!(mock.strobes(), vec![RfState::SIDLE as u8, RfState::SRX as u8]);
+    }
+
+    #[test]
+    fn wait_for_state() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        rfcat.wait_for_state(MainRadioControlState::Idle, Duration::from_millis(0)).unwrap();
+
+        // the radio gets to RX a little while after we start looking
+        let later = mock.clone();
+        let settle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            later.set_memory(MARCSTATE_ADDR, &[MainRadioControlState::Rx as u8]);
+        });
+        rfcat.wait_for_state(MainRadioControlState::Rx, Duration::from_secs(1)).unwrap();
+        settle.join().unwrap();
+
+        let start = std::time::Instant::now();
+        match rfcat.wait_for_state(MainRadioControlState::Tx, Duration::from_millis(20)) {
+            Err(Error::Timeout) => (),
+            other => panic!("waiting for a state that never comes: {:?}", other),
+        }
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn strobe_mode_return() {
+        for &(mode, state) in &[(RfState::SRX, MainRadioControlState::Rx), (RfState::STX, MainRadioControlState::Tx)] {
+            let mock = MockTransport::new();
+            let rfcat = open(&mock);
+            rfcat.set_rf_mode(mode).unwrap();
+            rfcat.set_sync_word(0x1234).unwrap();
+            assert_eq!(mock.memory(RADIO_BASE, 2), vec![0x12, 0x34]);
+            // idled for the write, then back to the mode it was set to
+            assert_eq!(mock.strobes(), vec![RfState::SIDLE as u8, mode as u8]);
+            assert_eq!(mock.memory(MARCSTATE_ADDR, 1), vec![state as u8]);
+        }
+
+        // nothing known about the mode: it stays idle
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        mock.set_memory(MARCSTATE_ADDR, &[MainRadioControlState::Rx as u8]);
+        rfcat.strobe_mode_return().unwrap();
+        assert_eq!(mock.strobes(), vec![RfState::SIDLE as u8]);
+        assert_eq!(mock.memory(MARCSTATE_ADDR, 1), vec![MainRadioControlState::Idle as u8]);
+    }
+
+    #[test]
+    fn bootloader_magic() {
+        let rfcat = open(&MockTransport::new());
+        assert!(rfcat.has_bootloader().unwrap());
+        let rfcat = open(&MockTransport::new().with_bootloader(false));
+        assert!(!rfcat.has_bootloader().unwrap());
+        assert!(rfcat.bootloader().is_err());
+    }
+
+    #[test]
+    fn bootloader_detaches() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        assert!(rfcat.bootloader().unwrap());
+        assert!(mock.detached());
+        match rfcat.ping() {
+            Err(Error::Disconnected) => (),
+            other => panic!("ping after bootloader: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn radio_config() {
+        let mock = MockTransport::new();
+        let rfcat = open(&mock);
+        let config = rfcat.get_radio_config().unwrap();
+        assert_eq!(config.to_bytes()[..], RADIO_DEFAULTS[..]);
+        assert_eq!(config.sync_word(), 0xd391);
+        assert_eq!(rfcat.last_radio_config().map(|c| c.to_bytes()), Some(config.to_bytes()));
+
+        mock.set_memory(0xdf00, &[0x12, 0x34]);
+        assert_eq!(rfcat.get_sync_word().unwrap(), 0x1234);
+    }
+}
@@ -0,0 +1,421 @@
+/*
+ * radio profiles: a RadioConfig as a TOML (or JSON) file people can read,
+ * edit and keep around per target.  a profile has the high-level settings
+ * (frequency, modulation, ...) and the raw register bytes; either half can
+ * be left out.  applying one sets the registers first, then any setting
+ * that doesn't already agree with them, so a saved profile loads back
+ * byte for byte and a hand-edited frequency still wins.  SmartRF Studio
+ * exports and rfcat byte strings load as profiles too (interop.rs).
+ */
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::registers;
+use crate::{CCRegisters, ModulationFormat, RadioConfig, SyncM, RADIO_CONFIG_WRITABLE_LEN};
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ProfileFormat {
+    Toml,
+    Json,
+    // SmartRF Studio register export
+    SmartRf,
+    // rfcat's RadioConfig byte string
+    RfCat,
+}
+
+impl ProfileFormat {
+
+    /* *.json is JSON, *.h a SmartRF Studio export, everything else TOML */
+    pub fn from_path(path: &str) -> ProfileFormat {
+        let path = path.to_ascii_lowercase();
+        if path.ends_with(".json") {
+            ProfileFormat::Json
+        } else if path.ends_with(".h") {
+            ProfileFormat::SmartRf
+        } else {
+            ProfileFormat::Toml
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ProfileFormat> {
+        match name.to_ascii_lowercase().as_str() {
+            "toml" => Some(ProfileFormat::Toml),
+            "json" => Some(ProfileFormat::Json),
+            "smartrf" => Some(ProfileFormat::SmartRf),
+            "rfcat" => Some(ProfileFormat::RfCat),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RadioProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    // Hz
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<u64>,
+    // MDMCFG2 MOD_FORMAT by name: 2_FSK, GFSK, ASK_OOK, MSK
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modulation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baud: Option<f64>,
+    // FSK deviation, Hz
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deviation: Option<f64>,
+    // RX channel filter bandwidth, Hz
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bandwidth: Option<f64>,
+    // hex, "0xd391"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_word: Option<String>,
+    // MDMCFG2 SYNC_MODE by name: NO_PRE, 15_16, 16_16, 30_32, ..._CS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_mode: Option<String>,
+    // last, so it comes out as a [registers] table after the plain values
+    #[serde(default, skip_serializing_if = "RegisterBytes::is_empty")]
+    pub registers: RegisterBytes,
+}
+
+impl RadioProfile {
+
+    pub fn parse(text: &str, format: ProfileFormat) -> Result<RadioProfile, Error> {
+        let parsed = match format {
+            ProfileFormat::Toml => toml::from_str(text).map_err(|err| err.to_string()),
+            ProfileFormat::Json => serde_json::from_str(text).map_err(|err| err.to_string()),
+            ProfileFormat::SmartRf => return RadioProfile::from_smartrf(text),
+            ProfileFormat::RfCat => return RadioProfile::from_rfcat(text),
+        };
+        match parsed {
+            Ok(profile) => Ok(profile),
+            Err(err) => Err(Error::InvalidArgument(format!("radio profile: {}", err))),
+        }
+    }
+
+    /* TOML or JSON; the other formats want a whole RadioConfig (RadioConfig::export) */
+    pub fn to_text(&self, format: ProfileFormat) -> Result<String, Error> {
+        let text = match format {
+            ProfileFormat::Toml => toml::to_string(self).map_err(|err| err.to_string()),
+            ProfileFormat::Json => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+            ProfileFormat::SmartRf | ProfileFormat::RfCat => {
+                return Err(Error::Unsupported("SmartRF / rfcat export of a profile"));
+            },
+        };
+        match text {
+            Ok(text) => Ok(text),
+            Err(err) => Err(Error::InvalidArgument(format!("radio profile: {}", err))),
+        }
+    }
+}
+
+/*
+ * writable radio registers by address; in a profile it's a table of
+ * NAME = "0x.." in address order (plain numbers are fine going in)
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegisterBytes(pub BTreeMap<u16, u8>);
+
+impl RegisterBytes {
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Serialize for RegisterBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (addr, value) in self.0.iter() {
+            match registers::by_xdata(*addr) {
+                Some(reg) => map.serialize_entry(reg.name, &format!("0x{:02x}", value))?,
+                None => map.serialize_entry(&format!("0x{:04x}", addr), &format!("0x{:02x}", value))?,
+            }
+        }
+        map.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ByteValue {
+    Number(u8),
+    Text(String),
+}
+
+impl<'de> Deserialize<'de> for RegisterBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let named = BTreeMap::<String, ByteValue>::deserialize(deserializer)?;
+        let mut bytes = BTreeMap::<u16, u8>::new();
+        for (name, value) in named {
+            let addr = match writable_register(&name) {
+                Some(addr) => addr,
+                None => {
+                    return Err(de::Error::custom(format!("{} is not a writable radio register", name)));
+                },
+            };
+            let value = match value {
+                ByteValue::Number(value) => value,
+                ByteValue::Text(text) => match u8::from_str_radix(text.trim_start_matches("0x"), 16) {
+                    Ok(value) => value,
+                    Err(_) => {
+                        return Err(de::Error::custom(format!("{} = {:?} is not a hex byte", name, text)));
+                    },
+                },
+            };
+            bytes.insert(addr, value);
+        }
+        Ok(RegisterBytes(bytes))
+    }
+}
+
+/* a radio register name (or 0x address) that a profile is allowed to set */
+fn writable_register(name: &str) -> Option<u16> {
+    let addr = match registers::by_name(name) {
+        Some(reg) => reg.xdata?,
+        None => u16::from_str_radix(name.strip_prefix("0x")?, 16).ok()?,
+    };
+    writable_offset(addr).map(|_| addr)
+}
+
+/* where addr sits in the writable part of the radio block, if it does */
+fn writable_offset(addr: u16) -> Option<usize> {
+    let base = CCRegisters::Sync1 as u16;
+    match addr >= base && addr < base + RADIO_CONFIG_WRITABLE_LEN {
+        true => Some((addr - base) as usize),
+        false => None,
+    }
+}
+
+impl fmt::Display for RadioProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::<String>::new();
+        if let Some(name) = &self.name {
+            parts.push(name.clone());
+        }
+        if let Some(hz) = self.frequency {
+            parts.push(format!("{} Hz", hz));
+        }
+        if let Some(modulation) = &self.modulation {
+            parts.push(modulation.clone());
+        }
+        if let Some(baud) = self.baud {
+            parts.push(format!("{} baud", baud));
+        }
+        if let Some(hz) = self.bandwidth {
+            parts.push(format!("{} Hz wide", hz));
+        }
+        if let Some(word) = &self.sync_word {
+            parts.push(format!("sync {}", word));
+        }
+        if !self.registers.is_empty() {
+            parts.push(format!("{} registers", self.registers.0.len()));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl RadioConfig {
+
+    /* this config in any of the formats; name only goes into TOML / JSON */
+    pub fn export(&self, format: ProfileFormat, name: Option<&str>) -> Result<String, Error> {
+        match format {
+            ProfileFormat::SmartRf => Ok(self.to_smartrf()),
+            ProfileFormat::RfCat => Ok(format!("{}\n", self.to_rfcat())),
+            ProfileFormat::Toml | ProfileFormat::Json => {
+                let mut profile = self.to_profile();
+                profile.name = name.map(String::from);
+                profile.to_text(format)
+            },
+        }
+    }
+
+    /* everything a profile holds, from this config; rates rounded to the Hz */
+    pub fn to_profile(&self) -> RadioProfile {
+        let base = CCRegisters::Sync1 as u16;
+        let bytes = self.to_bytes();
+        let mut regs = BTreeMap::<u16, u8>::new();
+        for reg in registers::radio() {
+            let addr = reg.xdata.unwrap();
+            if addr < base + RADIO_CONFIG_WRITABLE_LEN {
+                regs.insert(addr, bytes[(addr - base) as usize]);
+            }
+        }
+        RadioProfile{
+            name: None,
+            frequency: Some(self.frequency()),
+            modulation: registers::MDMCFG2_MOD_FORMAT.value_name(self.mdmcfg2).map(String::from),
+            baud: Some(self.data_rate().round()),
+            deviation: Some(self.deviation().round()),
+            bandwidth: Some(self.channel_bandwidth().round()),
+            sync_word: Some(format!("0x{:04x}", self.sync_word())),
+            sync_mode: registers::MDMCFG2_SYNC_MODE.value_name(self.mdmcfg2).map(String::from),
+            registers: RegisterBytes(regs),
+        }
+    }
+
+    /*
+     * the profile's registers, then its settings; a setting only gets applied
+     * when it would change something, so it doesn't undo the registers
+     * (set_channel_bandwidth rewrites FREND1/TEST*, set_frequency FSCAL2, ...)
+     */
+    pub fn apply_profile(&mut self, profile: &RadioProfile) -> Result<(), Error> {
+        if !profile.registers.is_empty() {
+            let mut bytes = self.to_bytes();
+            // RegisterBytes can be built by hand, so the deserializer's check isn't enough
+            for (addr, value) in profile.registers.0.iter() {
+                match writable_offset(*addr) {
+                    Some(offset) => bytes[offset] = *value,
+                    None => {
+                        return Err(Error::InvalidArgument(format!("0x{:04x} is not a writable radio register", addr)));
+                    },
+                }
+            }
+            *self = RadioConfig::from_bytes(&bytes[..])?;
+        }
+
+        // a saved frequency is exactly what the registers give, so that's
+        // left alone even when set_frequency would refuse it (FREQ out of reset)
+        if let Some(hz) = profile.frequency.filter(|&hz| hz != self.frequency()) {
+            let mut probe = *self;
+            if probe.set_frequency(hz)? != self.frequency() {
+                *self = probe;
+            }
+        }
+        if let Some(name) = &profile.modulation {
+            let modulation = match registers::MDMCFG2_MOD_FORMAT.value(name) {
+                Some(value) => ModulationFormat::from(value),
+                None => {
+                    return Err(Error::InvalidArgument(format!("modulation {:?} (want one of {})", name, value_names(&registers::MDMCFG2_MOD_FORMAT))));
+                },
+            };
+            if modulation != self.modulation() {
+                self.set_modulation(modulation)?;
+            }
+        }
+        if let Some(baud) = profile.baud {
+            let mut probe = *self;
+            if probe.set_data_rate(baud)? != self.data_rate() {
+                *self = probe;
+            }
+        }
+        if let Some(hz) = profile.deviation {
+            let mut probe = *self;
+            if probe.set_deviation(hz)? != self.deviation() {
+                *self = probe;
+            }
+        }
+        if let Some(hz) = profile.bandwidth {
+            let mut probe = *self;
+            if probe.set_channel_bandwidth(hz)? != self.channel_bandwidth() {
+                *self = probe;
+            }
+        }
+        if let Some(text) = &profile.sync_word {
+            match u16::from_str_radix(text.trim_start_matches("0x"), 16) {
+                Ok(word) if word != self.sync_word() => self.set_sync_word(word),
+                Ok(_) => (),
+                Err(_) => {
+                    return Err(Error::InvalidArgument(format!("sync word {:?}", text)));
+                },
+            }
+        }
+        if let Some(name) = &profile.sync_mode {
+            match registers::MDMCFG2_SYNC_MODE.value(name) {
+                Some(value) if SyncM::from(value) != self.sync_mode() => self.set_sync_mode(SyncM::from(value)),
+                Some(_) => (),
+                None => {
+                    return Err(Error::InvalidArgument(format!("sync mode {:?} (want one of {})", name, value_names(&registers::MDMCFG2_SYNC_MODE))));
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+fn value_names(field: &registers::Field) -> String {
+    field.values.iter().map(|v| v.name).collect::<Vec<&str>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::RADIO_DEFAULTS;
+
+    /* the radio out of reset, moved to a 433.92 MHz ASK/OOK remote */
+    fn ook_433() -> RadioConfig {
+        let mut config = RadioConfig::from_bytes(&RADIO_DEFAULTS).unwrap();
+        config.set_frequency(433_920_000).unwrap();
+        config.set_modulation(ModulationFormat::AskOok).unwrap();
+        config.set_data_rate(3200.0).unwrap();
+        config.set_channel_bandwidth(100e3).unwrap();
+        config.set_sync_word(0x8888);
+        config.set_sync_mode(SyncM::SM30of32);
+        config.pa_table1 = 0xc0;
+        config
+    }
+
+    fn writable(config: &RadioConfig) -> Vec<u8> {
+        config.to_bytes()[..RADIO_CONFIG_WRITABLE_LEN as usize].to_vec()
+    }
+
+    #[test]
+    fn round_trip() {
+        for config in &[RadioConfig::from_bytes(&RADIO_DEFAULTS).unwrap(), ook_433()] {
+            for &format in &[ProfileFormat::Toml, ProfileFormat::Json] {
+                let text = config.to_profile().to_text(format).unwrap();
+                let profile = RadioProfile::parse(&text, format).unwrap();
+                assert_eq!(profile, config.to_profile());
+                let mut loaded = RadioConfig::default();
+                loaded.apply_profile(&profile).unwrap();
+                assert_eq!(writable(&loaded), writable(config), "{:?}:\n{}", format, text);
+            }
+        }
+    }
+
+    #[test]
+    fn edited_setting_wins() {
+        let config = ook_433();
+        let text = config.to_profile().to_text(ProfileFormat::Toml).unwrap();
+        let text = text.replace(&format!("frequency = {}", config.frequency()), "frequency = 315000000")
+                       .replace("sync_word = \"0x8888\"", "sync_word = \"0xd391\"");
+        let profile = RadioProfile::parse(&text, ProfileFormat::Toml).unwrap();
+        assert_eq!(profile.frequency, Some(315_000_000));
+        let mut loaded = RadioConfig::default();
+        loaded.apply_profile(&profile).unwrap();
+        assert!((loaded.frequency() as i64 - 315_000_000).abs() < 400);
+        assert_eq!(loaded.sync_word(), 0xd391);
+        // the rest still comes from the registers
+        assert_eq!(loaded.modulation(), ModulationFormat::AskOok);
+        assert_eq!(loaded.sync_mode(), SyncM::SM30of32);
+        assert_eq!((loaded.mdmcfg4, loaded.mdmcfg3, loaded.frend1), (config.mdmcfg4, config.mdmcfg3, config.frend1));
+    }
+
+    #[test]
+    fn settings_without_registers() {
+        let text = "frequency = 433920000\nmodulation = \"ASK_OOK\"\nbaud = 4800\nsync_mode = \"NO_PRE\"\n";
+        let mut config = RadioConfig::from_bytes(&RADIO_DEFAULTS).unwrap();
+        config.apply_profile(&RadioProfile::parse(text, ProfileFormat::Toml).unwrap()).unwrap();
+        assert_eq!(config.modulation(), ModulationFormat::AskOok);
+        assert!((config.data_rate() - 4800.0).abs() < 5.0);
+        assert_eq!(config.sync_mode(), SyncM::SMNone);
+
+        let bad = RadioProfile::parse("modulation = \"FM\"\n", ProfileFormat::Toml).unwrap();
+        assert!(config.apply_profile(&bad).is_err());
+        for &addr in &[0x0000, 0xdeff, 0xdf3b, 0xdf80] {
+            let mut bad = RadioProfile::default();
+            bad.registers.0.insert(addr, 0x01);
+            match config.apply_profile(&bad) {
+                Err(Error::InvalidArgument(_)) => (),
+                other => panic!("register 0x{:04x}: {:?}", addr, other),
+            }
+        }
+        assert!(RadioProfile::parse("[registers]\nMARCSTATE = \"0x01\"\n", ProfileFormat::Toml).is_err());
+        assert!(RadioProfile::parse("[registers]\nMDMCFG2 = \"0xzz\"\n", ProfileFormat::Toml).is_err());
+    }
+}
@@ -0,0 +1,1411 @@
+/*
+ * the CC1111's registers: every SFR and XDATA register by name and address,
+ * and the bitfields inside them.  built from the constants (rfcat's
+ * cc1111.py, more or less) that used to sit commented out in transport.rs,
+ * with the datasheet filling in where those were missing or wrong.
+ */
+
+use crate::{CCRegisters, RADIO_CONFIG_LEN};
+
+/* one named setting of a field, already shifted into place */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldValue {
+    pub name: &'static str,
+    pub value: u8,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    pub name: &'static str,
+    pub mask: u8,
+    // the settings with names; empty for plain flags and numbers
+    pub values: &'static [FieldValue],
+}
+
+impl Field {
+
+    pub fn shift(&self) -> u32 {
+        self.mask.trailing_zeros()
+    }
+
+    /* this field out of a register value, shifted down */
+    pub fn get(&self, reg: u8) -> u8 {
+        (reg & self.mask) >> self.shift()
+    }
+
+    /* reg with this field replaced by value (unshifted) */
+    pub fn set(&self, reg: u8, value: u8) -> u8 {
+        (reg & !self.mask) | ((value << self.shift()) & self.mask)
+    }
+
+    pub fn is_set(&self, reg: u8) -> bool {
+        reg & self.mask != 0
+    }
+
+    /* the name of the setting reg has this field at, if it has one */
+    pub fn value_name(&self, reg: u8) -> Option<&'static str> {
+        self.values.iter().find(|v| v.value == reg & self.mask).map(|v| v.name)
+    }
+
+    /* the (shifted into place) value of a named setting */
+    pub fn value(&self, name: &str) -> Option<u8> {
+        self.values.iter().find(|v| v.name.eq_ignore_ascii_case(name)).map(|v| v.value)
+    }
+
+    /*
+     * this field of reg, for people: "MOD_FORMAT=GFSK", "DRATE_E=10", or
+     * "CRC_EN" for a flag (None when the flag is clear)
+     */
+    pub fn decode(&self, reg: u8) -> Option<String> {
+        if let Some(name) = self.value_name(reg) {
+            return Some(format!("{}={}", self.name, name));
+        }
+        match self.mask.count_ones() {
+            1 if self.values.is_empty() => match self.is_set(reg) {
+                true => Some(self.name.to_string()),
+                false => None,
+            },
+            _ => Some(format!("{}={}", self.name, self.get(reg))),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Register {
+    pub name: &'static str,
+    // where it is in SFR space, for SFRs
+    pub sfr: Option<u8>,
+    // where peek / poke get at it (SFRs too, for the ones mapped into XDATA)
+    pub xdata: Option<u16>,
+    // most significant first
+    pub fields: &'static [Field],
+}
+
+impl Register {
+
+    pub fn field(&self, name: &str) -> Option<&'static Field> {
+        self.fields.iter().find(|f| f.name.eq_ignore_ascii_case(name))
+    }
+
+    /* every field of value worth mentioning, most significant first */
+    pub fn decode(&self, value: u8) -> Vec<String> {
+        let mut decoded = Vec::<String>::new();
+        for (i, field) in self.fields.iter().enumerate() {
+            // some bits have two names (IEN2's P2IE / USBIE); say it once
+            if self.fields[..i].iter().any(|f| f.mask == field.mask) {
+                continue
+            }
+            if let Some(d) = field.decode(value) {
+                decoded.push(d);
+            }
+        }
+        decoded
+    }
+}
+
+/* look a register up by name, ignoring case; X_<name> finds the SFR too */
+pub fn by_name(name: &str) -> Option<&'static Register> {
+    let name = match name.get(..2) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("X_") => &name[2..],
+        _ => name,
+    };
+    REGISTERS.iter().find(|r| r.name.eq_ignore_ascii_case(name))
+}
+
+/* the register at an XDATA address (the first name, where two share one) */
+pub fn by_xdata(addr: u16) -> Option<&'static Register> {
+    REGISTERS.iter().find(|r| r.xdata == Some(addr))
+}
+
+pub fn by_sfr(addr: u8) -> Option<&'static Register> {
+    REGISTERS.iter().find(|r| r.sfr == Some(addr))
+}
+
+/* the radio's registers, the block get_radio_config reads (0xDF00 - 0xDF3D) */
+pub fn radio() -> impl Iterator<Item = &'static Register> {
+    let base = CCRegisters::Sync1 as u16;
+    REGISTERS.iter().filter(move |r| match r.xdata {
+        Some(addr) => addr >= base && addr < base + RADIO_CONFIG_LEN,
+        None => false,
+    })
+}
+
+// USB controller, XDATA 0xDE00 - 0xDE2A
+pub const USBADDR_UPDATE: Field = Field{name: "UPDATE", mask: 0x80, values: &[]};
+pub const USBADDR: Register = Register{name: "USBADDR", sfr: None, xdata: Some(0xDE00), fields: &[
+    USBADDR_UPDATE,
+]};
+pub const USBPOW_ISO_WAIT_SOF: Field = Field{name: "ISO_WAIT_SOF", mask: 0x80, values: &[]};
+pub const USBPOW_RST: Field = Field{name: "RST", mask: 0x08, values: &[]};
+pub const USBPOW_RESUME: Field = Field{name: "RESUME", mask: 0x04, values: &[]};
+pub const USBPOW_SUSPEND: Field = Field{name: "SUSPEND", mask: 0x02, values: &[]};
+pub const USBPOW_SUSPEND_EN: Field = Field{name: "SUSPEND_EN", mask: 0x01, values: &[]};
+pub const USBPOW: Register = Register{name: "USBPOW", sfr: None, xdata: Some(0xDE01), fields: &[
+    USBPOW_ISO_WAIT_SOF, USBPOW_RST, USBPOW_RESUME, USBPOW_SUSPEND, USBPOW_SUSPEND_EN,
+]};
+pub const USBIIF_INEP5IF: Field = Field{name: "INEP5IF", mask: 0x20, values: &[]};
+pub const USBIIF_INEP4IF: Field = Field{name: "INEP4IF", mask: 0x10, values: &[]};
+pub const USBIIF_INEP3IF: Field = Field{name: "INEP3IF", mask: 0x08, values: &[]};
+pub const USBIIF_INEP2IF: Field = Field{name: "INEP2IF", mask: 0x04, values: &[]};
+pub const USBIIF_INEP1IF: Field = Field{name: "INEP1IF", mask: 0x02, values: &[]};
+pub const USBIIF_EP0IF: Field = Field{name: "EP0IF", mask: 0x01, values: &[]};
+pub const USBIIF: Register = Register{name: "USBIIF", sfr: None, xdata: Some(0xDE02), fields: &[
+    USBIIF_INEP5IF, USBIIF_INEP4IF, USBIIF_INEP3IF, USBIIF_INEP2IF, USBIIF_INEP1IF, USBIIF_EP0IF,
+]};
+pub const USBOIF_OUTEP5IF: Field = Field{name: "OUTEP5IF", mask: 0x20, values: &[]};
+pub const USBOIF_OUTEP4IF: Field = Field{name: "OUTEP4IF", mask: 0x10, values: &[]};
+pub const USBOIF_OUTEP3IF: Field = Field{name: "OUTEP3IF", mask: 0x08, values: &[]};
+pub const USBOIF_OUTEP2IF: Field = Field{name: "OUTEP2IF", mask: 0x04, values: &[]};
+pub const USBOIF_OUTEP1IF: Field = Field{name: "OUTEP1IF", mask: 0x02, values: &[]};
+pub const USBOIF: Register = Register{name: "USBOIF", sfr: None, xdata: Some(0xDE04), fields: &[
+    USBOIF_OUTEP5IF, USBOIF_OUTEP4IF, USBOIF_OUTEP3IF, USBOIF_OUTEP2IF, USBOIF_OUTEP1IF,
+]};
+pub const USBCIF_SOFIF: Field = Field{name: "SOFIF", mask: 0x08, values: &[]};
+pub const USBCIF_RSTIF: Field = Field{name: "RSTIF", mask: 0x04, values: &[]};
+pub const USBCIF_RESUMEIF: Field = Field{name: "RESUMEIF", mask: 0x02, values: &[]};
+pub const USBCIF_SUSPENDIF: Field = Field{name: "SUSPENDIF", mask: 0x01, values: &[]};
+pub const USBCIF: Register = Register{name: "USBCIF", sfr: None, xdata: Some(0xDE06), fields: &[
+    USBCIF_SOFIF, USBCIF_RSTIF, USBCIF_RESUMEIF, USBCIF_SUSPENDIF,
+]};
+pub const USBIIE_INEP5IE: Field = Field{name: "INEP5IE", mask: 0x20, values: &[]};
+pub const USBIIE_INEP4IE: Field = Field{name: "INEP4IE", mask: 0x10, values: &[]};
+pub const USBIIE_INEP3IE: Field = Field{name: "INEP3IE", mask: 0x08, values: &[]};
+pub const USBIIE_INEP2IE: Field = Field{name: "INEP2IE", mask: 0x04, values: &[]};
+pub const USBIIE_INEP1IE: Field = Field{name: "INEP1IE", mask: 0x02, values: &[]};
+pub const USBIIE_EP0IE: Field = Field{name: "EP0IE", mask: 0x01, values: &[]};
+pub const USBIIE: Register = Register{name: "USBIIE", sfr: None, xdata: Some(0xDE07), fields: &[
+    USBIIE_INEP5IE, USBIIE_INEP4IE, USBIIE_INEP3IE, USBIIE_INEP2IE, USBIIE_INEP1IE, USBIIE_EP0IE,
+]};
+pub const USBOIE_EP5IE: Field = Field{name: "EP5IE", mask: 0x20, values: &[]};
+pub const USBOIE_EP4IE: Field = Field{name: "EP4IE", mask: 0x10, values: &[]};
+pub const USBOIE_EP3IE: Field = Field{name: "EP3IE", mask: 0x08, values: &[]};
+pub const USBOIE_EP2IE: Field = Field{name: "EP2IE", mask: 0x04, values: &[]};
+pub const USBOIE_EP1IE: Field = Field{name: "EP1IE", mask: 0x02, values: &[]};
+pub const USBOIE: Register = Register{name: "USBOIE", sfr: None, xdata: Some(0xDE09), fields: &[
+    USBOIE_EP5IE, USBOIE_EP4IE, USBOIE_EP3IE, USBOIE_EP2IE, USBOIE_EP1IE,
+]};
+pub const USBCIE_SOFIE: Field = Field{name: "SOFIE", mask: 0x08, values: &[]};
+pub const USBCIE_RSTIE: Field = Field{name: "RSTIE", mask: 0x04, values: &[]};
+pub const USBCIE_RESUMEIE: Field = Field{name: "RESUMEIE", mask: 0x02, values: &[]};
+pub const USBCIE_SUSPENDIE: Field = Field{name: "SUSPENDIE", mask: 0x01, values: &[]};
+pub const USBCIE: Register = Register{name: "USBCIE", sfr: None, xdata: Some(0xDE0B), fields: &[
+    USBCIE_SOFIE, USBCIE_RSTIE, USBCIE_RESUMEIE, USBCIE_SUSPENDIE,
+]};
+pub const USBFRML: Register = Register{name: "USBFRML", sfr: None, xdata: Some(0xDE0C), fields: &[]};
+pub const USBFRMH: Register = Register{name: "USBFRMH", sfr: None, xdata: Some(0xDE0D), fields: &[]};
+pub const USBINDEX: Register = Register{name: "USBINDEX", sfr: None, xdata: Some(0xDE0E), fields: &[]};
+pub const USBMAXI: Register = Register{name: "USBMAXI", sfr: None, xdata: Some(0xDE10), fields: &[]};
+pub const USBCS0_CLR_SETUP_END: Field = Field{name: "CLR_SETUP_END", mask: 0x80, values: &[]};
+pub const USBCS0_CLR_OUTPKT_RDY: Field = Field{name: "CLR_OUTPKT_RDY", mask: 0x40, values: &[]};
+pub const USBCS0_SEND_STALL: Field = Field{name: "SEND_STALL", mask: 0x20, values: &[]};
+pub const USBCS0_SETUP_END: Field = Field{name: "SETUP_END", mask: 0x10, values: &[]};
+pub const USBCS0_DATA_END: Field = Field{name: "DATA_END", mask: 0x08, values: &[]};
+pub const USBCS0_SENT_STALL: Field = Field{name: "SENT_STALL", mask: 0x04, values: &[]};
+pub const USBCS0_INPKT_RDY: Field = Field{name: "INPKT_RDY", mask: 0x02, values: &[]};
+pub const USBCS0_OUTPKT_RDY: Field = Field{name: "OUTPKT_RDY", mask: 0x01, values: &[]};
+pub const USBCS0: Register = Register{name: "USBCS0", sfr: None, xdata: Some(0xDE11), fields: &[
+    USBCS0_CLR_SETUP_END, USBCS0_CLR_OUTPKT_RDY, USBCS0_SEND_STALL, USBCS0_SETUP_END,
+    USBCS0_DATA_END, USBCS0_SENT_STALL, USBCS0_INPKT_RDY, USBCS0_OUTPKT_RDY,
+]};
+pub const USBCSIL_CLR_DATA_TOG: Field = Field{name: "CLR_DATA_TOG", mask: 0x40, values: &[]};
+pub const USBCSIL_SENT_STALL: Field = Field{name: "SENT_STALL", mask: 0x20, values: &[]};
+pub const USBCSIL_SEND_STALL: Field = Field{name: "SEND_STALL", mask: 0x10, values: &[]};
+pub const USBCSIL_FLUSH_PACKET: Field = Field{name: "FLUSH_PACKET", mask: 0x08, values: &[]};
+pub const USBCSIL_UNDERRUN: Field = Field{name: "UNDERRUN", mask: 0x04, values: &[]};
+pub const USBCSIL_PKT_PRESENT: Field = Field{name: "PKT_PRESENT", mask: 0x02, values: &[]};
+pub const USBCSIL_INPKT_RDY: Field = Field{name: "INPKT_RDY", mask: 0x01, values: &[]};
+pub const USBCSIL: Register = Register{name: "USBCSIL", sfr: None, xdata: Some(0xDE11), fields: &[
+    USBCSIL_CLR_DATA_TOG, USBCSIL_SENT_STALL, USBCSIL_SEND_STALL, USBCSIL_FLUSH_PACKET,
+    USBCSIL_UNDERRUN, USBCSIL_PKT_PRESENT, USBCSIL_INPKT_RDY,
+]};
+pub const USBCSIH_AUTOSET: Field = Field{name: "AUTOSET", mask: 0x80, values: &[]};
+pub const USBCSIH_ISO: Field = Field{name: "ISO", mask: 0x40, values: &[]};
+pub const USBCSIH_FORCE_DATA_TOG: Field = Field{name: "FORCE_DATA_TOG", mask: 0x08, values: &[]};
+pub const USBCSIH_IN_DBL_BUF: Field = Field{name: "IN_DBL_BUF", mask: 0x01, values: &[]};
+pub const USBCSIH: Register = Register{name: "USBCSIH", sfr: None, xdata: Some(0xDE12), fields: &[
+    USBCSIH_AUTOSET, USBCSIH_ISO, USBCSIH_FORCE_DATA_TOG, USBCSIH_IN_DBL_BUF,
+]};
+pub const USBMAXO: Register = Register{name: "USBMAXO", sfr: None, xdata: Some(0xDE13), fields: &[]};
+pub const USBCSOL_CLR_DATA_TOG: Field = Field{name: "CLR_DATA_TOG", mask: 0x80, values: &[]};
+pub const USBCSOL_SENT_STALL: Field = Field{name: "SENT_STALL", mask: 0x40, values: &[]};
+pub const USBCSOL_SEND_STALL: Field = Field{name: "SEND_STALL", mask: 0x20, values: &[]};
+pub const USBCSOL_FLUSH_PACKET: Field = Field{name: "FLUSH_PACKET", mask: 0x10, values: &[]};
+pub const USBCSOL_DATA_ERROR: Field = Field{name: "DATA_ERROR", mask: 0x08, values: &[]};
+pub const USBCSOL_OVERRUN: Field = Field{name: "OVERRUN", mask: 0x04, values: &[]};
+pub const USBCSOL_FIFO_FULL: Field = Field{name: "FIFO_FULL", mask: 0x02, values: &[]};
+pub const USBCSOL_OUTPKT_RDY: Field = Field{name: "OUTPKT_RDY", mask: 0x01, values: &[]};
+pub const USBCSOL: Register = Register{name: "USBCSOL", sfr: None, xdata: Some(0xDE14), fields: &[
+    USBCSOL_CLR_DATA_TOG, USBCSOL_SENT_STALL, USBCSOL_SEND_STALL, USBCSOL_FLUSH_PACKET,
+    USBCSOL_DATA_ERROR, USBCSOL_OVERRUN, USBCSOL_FIFO_FULL, USBCSOL_OUTPKT_RDY,
+]};
+pub const USBCSOH_AUTOCLEAR: Field = Field{name: "AUTOCLEAR", mask: 0x80, values: &[]};
+pub const USBCSOH_ISO: Field = Field{name: "ISO", mask: 0x40, values: &[]};
+pub const USBCSOH_OUT_DBL_BUF: Field = Field{name: "OUT_DBL_BUF", mask: 0x01, values: &[]};
+pub const USBCSOH: Register = Register{name: "USBCSOH", sfr: None, xdata: Some(0xDE15), fields: &[
+    USBCSOH_AUTOCLEAR, USBCSOH_ISO, USBCSOH_OUT_DBL_BUF,
+]};
+pub const USBCNT0: Register = Register{name: "USBCNT0", sfr: None, xdata: Some(0xDE16), fields: &[]};
+pub const USBCNTL: Register = Register{name: "USBCNTL", sfr: None, xdata: Some(0xDE16), fields: &[]};
+pub const USBCNTH: Register = Register{name: "USBCNTH", sfr: None, xdata: Some(0xDE17), fields: &[]};
+pub const USBF0: Register = Register{name: "USBF0", sfr: None, xdata: Some(0xDE20), fields: &[]};
+pub const USBF1: Register = Register{name: "USBF1", sfr: None, xdata: Some(0xDE22), fields: &[]};
+pub const USBF2: Register = Register{name: "USBF2", sfr: None, xdata: Some(0xDE24), fields: &[]};
+pub const USBF3: Register = Register{name: "USBF3", sfr: None, xdata: Some(0xDE26), fields: &[]};
+pub const USBF4: Register = Register{name: "USBF4", sfr: None, xdata: Some(0xDE28), fields: &[]};
+pub const USBF5: Register = Register{name: "USBF5", sfr: None, xdata: Some(0xDE2A), fields: &[]};
+
+// radio, XDATA 0xDF00 - 0xDF3D
+pub const SYNC1: Register = Register{name: "SYNC1", sfr: None, xdata: Some(0xDF00), fields: &[]};
+pub const SYNC0: Register = Register{name: "SYNC0", sfr: None, xdata: Some(0xDF01), fields: &[]};
+pub const PKTLEN: Register = Register{name: "PKTLEN", sfr: None, xdata: Some(0xDF02), fields: &[]};
+pub const PKTCTRL1_PQT: Field = Field{name: "PQT", mask: 0xE0, values: &[]};
+pub const PKTCTRL1_APPEND_STATUS: Field = Field{name: "APPEND_STATUS", mask: 0x04, values: &[]};
+pub const PKTCTRL1_ADR_CHK: Field = Field{name: "ADR_CHK", mask: 0x03, values: &[
+    FieldValue{name: "NONE", value: 0x00},
+    FieldValue{name: "NO_BRDCST", value: 0x01},
+    FieldValue{name: "0_BRDCST", value: 0x02},
+    FieldValue{name: "0_255_BRDCST", value: 0x03},
+]};
+pub const PKTCTRL1: Register = Register{name: "PKTCTRL1", sfr: None, xdata: Some(0xDF03), fields: &[
+    PKTCTRL1_PQT, PKTCTRL1_APPEND_STATUS, PKTCTRL1_ADR_CHK,
+]};
+pub const PKTCTRL0_WHITE_DATA: Field = Field{name: "WHITE_DATA", mask: 0x40, values: &[]};
+pub const PKTCTRL0_PKT_FORMAT: Field = Field{name: "PKT_FORMAT", mask: 0x30, values: &[
+    FieldValue{name: "NORMAL", value: 0x00},
+    FieldValue{name: "SYNC_SERIAL", value: 0x10},
+    FieldValue{name: "RANDOM_TX", value: 0x20},
+    FieldValue{name: "ASYNC_SERIAL", value: 0x30},
+]};
+pub const PKTCTRL0_CC2400_EN: Field = Field{name: "CC2400_EN", mask: 0x08, values: &[]};
+pub const PKTCTRL0_CRC_EN: Field = Field{name: "CRC_EN", mask: 0x04, values: &[]};
+pub const PKTCTRL0_LENGTH_CONFIG: Field = Field{name: "LENGTH_CONFIG", mask: 0x03, values: &[
+    FieldValue{name: "FIX", value: 0x00},
+    FieldValue{name: "VAR", value: 0x01},
+]};
+pub const PKTCTRL0: Register = Register{name: "PKTCTRL0", sfr: None, xdata: Some(0xDF04), fields: &[
+    PKTCTRL0_WHITE_DATA, PKTCTRL0_PKT_FORMAT, PKTCTRL0_CC2400_EN, PKTCTRL0_CRC_EN,
+    PKTCTRL0_LENGTH_CONFIG,
+]};
+pub const ADDR: Register = Register{name: "ADDR", sfr: None, xdata: Some(0xDF05), fields: &[]};
+pub const CHANNR: Register = Register{name: "CHANNR", sfr: None, xdata: Some(0xDF06), fields: &[]};
+pub const FSCTRL1: Register = Register{name: "FSCTRL1", sfr: None, xdata: Some(0xDF07), fields: &[]};
+pub const FSCTRL0: Register = Register{name: "FSCTRL0", sfr: None, xdata: Some(0xDF08), fields: &[]};
+pub const FREQ2: Register = Register{name: "FREQ2", sfr: None, xdata: Some(0xDF09), fields: &[]};
+pub const FREQ1: Register = Register{name: "FREQ1", sfr: None, xdata: Some(0xDF0A), fields: &[]};
+pub const FREQ0: Register = Register{name: "FREQ0", sfr: None, xdata: Some(0xDF0B), fields: &[]};
+pub const MDMCFG4_CHANBW_E: Field = Field{name: "CHANBW_E", mask: 0xC0, values: &[]};
+pub const MDMCFG4_CHANBW_M: Field = Field{name: "CHANBW_M", mask: 0x30, values: &[]};
+pub const MDMCFG4_DRATE_E: Field = Field{name: "DRATE_E", mask: 0x0F, values: &[]};
+pub const MDMCFG4: Register = Register{name: "MDMCFG4", sfr: None, xdata: Some(0xDF0C), fields: &[
+    MDMCFG4_CHANBW_E, MDMCFG4_CHANBW_M, MDMCFG4_DRATE_E,
+]};
+pub const MDMCFG3: Register = Register{name: "MDMCFG3", sfr: None, xdata: Some(0xDF0D), fields: &[]};
+pub const MDMCFG2_DEM_DCFILT_OFF: Field = Field{name: "DEM_DCFILT_OFF", mask: 0x80, values: &[]};
+pub const MDMCFG2_MOD_FORMAT: Field = Field{name: "MOD_FORMAT", mask: 0x70, values: &[
+    FieldValue{name: "2_FSK", value: 0x00},
+    FieldValue{name: "GFSK", value: 0x10},
+    FieldValue{name: "ASK_OOK", value: 0x30},
+    FieldValue{name: "MSK", value: 0x70},
+]};
+pub const MDMCFG2_MANCHESTER_EN: Field = Field{name: "MANCHESTER_EN", mask: 0x08, values: &[]};
+pub const MDMCFG2_SYNC_MODE: Field = Field{name: "SYNC_MODE", mask: 0x07, values: &[
+    FieldValue{name: "NO_PRE", value: 0x00},
+    FieldValue{name: "15_16", value: 0x01},
+    FieldValue{name: "16_16", value: 0x02},
+    FieldValue{name: "30_32", value: 0x03},
+    FieldValue{name: "NO_PRE_CS", value: 0x04},
+    FieldValue{name: "15_16_CS", value: 0x05},
+    FieldValue{name: "16_16_CS", value: 0x06},
+    FieldValue{name: "30_32_CS", value: 0x07},
+]};
+pub const MDMCFG2: Register = Register{name: "MDMCFG2", sfr: None, xdata: Some(0xDF0E), fields: &[
+    MDMCFG2_DEM_DCFILT_OFF, MDMCFG2_MOD_FORMAT, MDMCFG2_MANCHESTER_EN, MDMCFG2_SYNC_MODE,
+]};
+pub const MDMCFG1_NUM_PREAMBLE: Field = Field{name: "NUM_PREAMBLE", mask: 0x70, values: &[
+    FieldValue{name: "2", value: 0x00},
+    FieldValue{name: "3", value: 0x10},
+    FieldValue{name: "4", value: 0x20},
+    FieldValue{name: "6", value: 0x30},
+    FieldValue{name: "8", value: 0x40},
+    FieldValue{name: "12", value: 0x50},
+    FieldValue{name: "16", value: 0x60},
+    FieldValue{name: "24", value: 0x70},
+]};
+pub const MDMCFG1_CHANSPC_E: Field = Field{name: "CHANSPC_E", mask: 0x03, values: &[]};
+pub const MDMCFG1: Register = Register{name: "MDMCFG1", sfr: None, xdata: Some(0xDF0F), fields: &[
+    MDMCFG1_NUM_PREAMBLE, MDMCFG1_CHANSPC_E,
+]};
+pub const MDMCFG0: Register = Register{name: "MDMCFG0", sfr: None, xdata: Some(0xDF10), fields: &[]};
+pub const DEVIATN_DEVIATION_E: Field = Field{name: "DEVIATION_E", mask: 0x70, values: &[]};
+pub const DEVIATN_DEVIATION_M: Field = Field{name: "DEVIATION_M", mask: 0x07, values: &[]};
+pub const DEVIATN: Register = Register{name: "DEVIATN", sfr: None, xdata: Some(0xDF11), fields: &[
+    DEVIATN_DEVIATION_E, DEVIATN_DEVIATION_M,
+]};
+pub const MCSM2_RX_TIME_RSSI: Field = Field{name: "RX_TIME_RSSI", mask: 0x10, values: &[]};
+pub const MCSM2_RX_TIME_QUAL: Field = Field{name: "RX_TIME_QUAL", mask: 0x08, values: &[]};
+pub const MCSM2_RX_TIME: Field = Field{name: "RX_TIME", mask: 0x07, values: &[]};
+pub const MCSM2: Register = Register{name: "MCSM2", sfr: None, xdata: Some(0xDF12), fields: &[
+    MCSM2_RX_TIME_RSSI, MCSM2_RX_TIME_QUAL, MCSM2_RX_TIME,
+]};
+pub const MCSM1_CCA_MODE: Field = Field{name: "CCA_MODE", mask: 0x30, values: &[
+    FieldValue{name: "ALWAYS", value: 0x00},
+    FieldValue{name: "RSSI0", value: 0x10},
+    FieldValue{name: "PACKET", value: 0x20},
+    FieldValue{name: "RSSI1", value: 0x30},
+]};
+pub const MCSM1_RXOFF_MODE: Field = Field{name: "RXOFF_MODE", mask: 0x0C, values: &[
+    FieldValue{name: "IDLE", value: 0x00},
+    FieldValue{name: "FSTXON", value: 0x04},
+    FieldValue{name: "TX", value: 0x08},
+    FieldValue{name: "RX", value: 0x0C},
+]};
+pub const MCSM1_TXOFF_MODE: Field = Field{name: "TXOFF_MODE", mask: 0x03, values: &[
+    FieldValue{name: "IDLE", value: 0x00},
+    FieldValue{name: "FSTXON", value: 0x01},
+    FieldValue{name: "TX", value: 0x02},
+    FieldValue{name: "RX", value: 0x03},
+]};
+pub const MCSM1: Register = Register{name: "MCSM1", sfr: None, xdata: Some(0xDF13), fields: &[
+    MCSM1_CCA_MODE, MCSM1_RXOFF_MODE, MCSM1_TXOFF_MODE,
+]};
+pub const MCSM0_FS_AUTOCAL: Field = Field{name: "FS_AUTOCAL", mask: 0x30, values: &[
+    FieldValue{name: "NEVER", value: 0x00},
+    FieldValue{name: "FROM_IDLE", value: 0x10},
+    FieldValue{name: "TO_IDLE", value: 0x20},
+    FieldValue{name: "4TH_TO_IDLE", value: 0x30},
+]};
+pub const MCSM0: Register = Register{name: "MCSM0", sfr: None, xdata: Some(0xDF14), fields: &[
+    MCSM0_FS_AUTOCAL,
+]};
+pub const FOCCFG_FOC_BS_CS_GATE: Field = Field{name: "FOC_BS_CS_GATE", mask: 0x20, values: &[]};
+pub const FOCCFG_FOC_PRE_K: Field = Field{name: "FOC_PRE_K", mask: 0x18, values: &[
+    FieldValue{name: "1K", value: 0x00},
+    FieldValue{name: "2K", value: 0x08},
+    FieldValue{name: "3K", value: 0x10},
+    FieldValue{name: "4K", value: 0x18},
+]};
+pub const FOCCFG_FOC_POST_K: Field = Field{name: "FOC_POST_K", mask: 0x04, values: &[]};
+pub const FOCCFG_FOC_LIMIT: Field = Field{name: "FOC_LIMIT", mask: 0x03, values: &[
+    FieldValue{name: "0", value: 0x00},
+    FieldValue{name: "DIV8", value: 0x01},
+    FieldValue{name: "DIV4", value: 0x02},
+    FieldValue{name: "DIV2", value: 0x03},
+]};
+pub const FOCCFG: Register = Register{name: "FOCCFG", sfr: None, xdata: Some(0xDF15), fields: &[
+    FOCCFG_FOC_BS_CS_GATE, FOCCFG_FOC_PRE_K, FOCCFG_FOC_POST_K, FOCCFG_FOC_LIMIT,
+]};
+pub const BSCFG_BS_PRE_KI: Field = Field{name: "BS_PRE_KI", mask: 0xC0, values: &[
+    FieldValue{name: "1K", value: 0x00},
+    FieldValue{name: "2K", value: 0x40},
+    FieldValue{name: "3K", value: 0x80},
+    FieldValue{name: "4K", value: 0xC0},
+]};
+pub const BSCFG_BS_PRE_KP: Field = Field{name: "BS_PRE_KP", mask: 0x30, values: &[
+    FieldValue{name: "1K", value: 0x00},
+    FieldValue{name: "2K", value: 0x10},
+    FieldValue{name: "3K", value: 0x20},
+    FieldValue{name: "4K", value: 0x30},
+]};
+pub const BSCFG_BS_POST_KI: Field = Field{name: "BS_POST_KI", mask: 0x08, values: &[]};
+pub const BSCFG_BS_POST_KP: Field = Field{name: "BS_POST_KP", mask: 0x04, values: &[]};
+pub const BSCFG_BS_LIMIT: Field = Field{name: "BS_LIMIT", mask: 0x03, values: &[
+    FieldValue{name: "0", value: 0x00},
+    FieldValue{name: "3", value: 0x01},
+    FieldValue{name: "6", value: 0x02},
+    FieldValue{name: "12", value: 0x03},
+]};
+pub const BSCFG: Register = Register{name: "BSCFG", sfr: None, xdata: Some(0xDF16), fields: &[
+    BSCFG_BS_PRE_KI, BSCFG_BS_PRE_KP, BSCFG_BS_POST_KI, BSCFG_BS_POST_KP, BSCFG_BS_LIMIT,
+]};
+pub const AGCCTRL2_MAX_DVGA_GAIN: Field = Field{name: "MAX_DVGA_GAIN", mask: 0xC0, values: &[]};
+pub const AGCCTRL2_MAX_LNA_GAIN: Field = Field{name: "MAX_LNA_GAIN", mask: 0x38, values: &[]};
+pub const AGCCTRL2_MAGN_TARGET: Field = Field{name: "MAGN_TARGET", mask: 0x07, values: &[]};
+pub const AGCCTRL2: Register = Register{name: "AGCCTRL2", sfr: None, xdata: Some(0xDF17), fields: &[
+    AGCCTRL2_MAX_DVGA_GAIN, AGCCTRL2_MAX_LNA_GAIN, AGCCTRL2_MAGN_TARGET,
+]};
+pub const AGCCTRL1_AGC_LNA_PRIORITY: Field = Field{name: "AGC_LNA_PRIORITY", mask: 0x40, values: &[]};
+pub const AGCCTRL1_CARRIER_SENSE_REL_THR: Field = Field{name: "CARRIER_SENSE_REL_THR", mask: 0x30, values: &[]};
+pub const AGCCTRL1_CARRIER_SENSE_ABS_THR: Field = Field{name: "CARRIER_SENSE_ABS_THR", mask: 0x0F, values: &[]};
+pub const AGCCTRL1: Register = Register{name: "AGCCTRL1", sfr: None, xdata: Some(0xDF18), fields: &[
+    AGCCTRL1_AGC_LNA_PRIORITY, AGCCTRL1_CARRIER_SENSE_REL_THR, AGCCTRL1_CARRIER_SENSE_ABS_THR,
+]};
+pub const AGCCTRL0_HYST_LEVEL: Field = Field{name: "HYST_LEVEL", mask: 0xC0, values: &[]};
+pub const AGCCTRL0_WAIT_TIME: Field = Field{name: "WAIT_TIME", mask: 0x30, values: &[]};
+pub const AGCCTRL0_AGC_FREEZE: Field = Field{name: "AGC_FREEZE", mask: 0x0C, values: &[]};
+pub const AGCCTRL0_FILTER_LENGTH: Field = Field{name: "FILTER_LENGTH", mask: 0x03, values: &[]};
+pub const AGCCTRL0: Register = Register{name: "AGCCTRL0", sfr: None, xdata: Some(0xDF19), fields: &[
+    AGCCTRL0_HYST_LEVEL, AGCCTRL0_WAIT_TIME, AGCCTRL0_AGC_FREEZE, AGCCTRL0_FILTER_LENGTH,
+]};
+pub const FREND1_LNA_CURRENT: Field = Field{name: "LNA_CURRENT", mask: 0xC0, values: &[]};
+pub const FREND1_LNA2MIX_CURRENT: Field = Field{name: "LNA2MIX_CURRENT", mask: 0x30, values: &[]};
+pub const FREND1_LODIV_BUF_CURRENT_RX: Field = Field{name: "LODIV_BUF_CURRENT_RX", mask: 0x0C, values: &[]};
+pub const FREND1_MIX_CURRENT: Field = Field{name: "MIX_CURRENT", mask: 0x03, values: &[]};
+pub const FREND1: Register = Register{name: "FREND1", sfr: None, xdata: Some(0xDF1A), fields: &[
+    FREND1_LNA_CURRENT, FREND1_LNA2MIX_CURRENT, FREND1_LODIV_BUF_CURRENT_RX, FREND1_MIX_CURRENT,
+]};
+pub const FREND0_LODIV_BUF_CURRENT_TX: Field = Field{name: "LODIV_BUF_CURRENT_TX", mask: 0x30, values: &[]};
+pub const FREND0_PA_POWER: Field = Field{name: "PA_POWER", mask: 0x07, values: &[]};
+pub const FREND0: Register = Register{name: "FREND0", sfr: None, xdata: Some(0xDF1B), fields: &[
+    FREND0_LODIV_BUF_CURRENT_TX, FREND0_PA_POWER,
+]};
+pub const FSCAL3_FSCAL3: Field = Field{name: "FSCAL3", mask: 0xC0, values: &[]};
+pub const FSCAL3_CHP_CURR_CAL_EN: Field = Field{name: "CHP_CURR_CAL_EN", mask: 0x30, values: &[]};
+pub const FSCAL3: Register = Register{name: "FSCAL3", sfr: None, xdata: Some(0xDF1C), fields: &[
+    FSCAL3_FSCAL3, FSCAL3_CHP_CURR_CAL_EN,
+]};
+pub const FSCAL2_VCO_CORE_H_EN: Field = Field{name: "VCO_CORE_H_EN", mask: 0x20, values: &[]};
+pub const FSCAL2_FSCAL2: Field = Field{name: "FSCAL2", mask: 0x1F, values: &[]};
+pub const FSCAL2: Register = Register{name: "FSCAL2", sfr: None, xdata: Some(0xDF1D), fields: &[
+    FSCAL2_VCO_CORE_H_EN, FSCAL2_FSCAL2,
+]};
+pub const FSCAL1: Register = Register{name: "FSCAL1", sfr: None, xdata: Some(0xDF1E), fields: &[]};
+pub const FSCAL0: Register = Register{name: "FSCAL0", sfr: None, xdata: Some(0xDF1F), fields: &[]};
+pub const TEST2: Register = Register{name: "TEST2", sfr: None, xdata: Some(0xDF23), fields: &[]};
+pub const TEST1: Register = Register{name: "TEST1", sfr: None, xdata: Some(0xDF24), fields: &[]};
+pub const TEST0: Register = Register{name: "TEST0", sfr: None, xdata: Some(0xDF25), fields: &[]};
+pub const PA_TABLE7: Register = Register{name: "PA_TABLE7", sfr: None, xdata: Some(0xDF27), fields: &[]};
+pub const PA_TABLE6: Register = Register{name: "PA_TABLE6", sfr: None, xdata: Some(0xDF28), fields: &[]};
+pub const PA_TABLE5: Register = Register{name: "PA_TABLE5", sfr: None, xdata: Some(0xDF29), fields: &[]};
+pub const PA_TABLE4: Register = Register{name: "PA_TABLE4", sfr: None, xdata: Some(0xDF2A), fields: &[]};
+pub const PA_TABLE3: Register = Register{name: "PA_TABLE3", sfr: None, xdata: Some(0xDF2B), fields: &[]};
+pub const PA_TABLE2: Register = Register{name: "PA_TABLE2", sfr: None, xdata: Some(0xDF2C), fields: &[]};
+pub const PA_TABLE1: Register = Register{name: "PA_TABLE1", sfr: None, xdata: Some(0xDF2D), fields: &[]};
+pub const PA_TABLE0: Register = Register{name: "PA_TABLE0", sfr: None, xdata: Some(0xDF2E), fields: &[]};
+pub const IOCFG2_GDO2_INV: Field = Field{name: "GDO2_INV", mask: 0x40, values: &[]};
+pub const IOCFG2_GDO2_CFG: Field = Field{name: "GDO2_CFG", mask: 0x3F, values: &[]};
+pub const IOCFG2: Register = Register{name: "IOCFG2", sfr: None, xdata: Some(0xDF2F), fields: &[
+    IOCFG2_GDO2_INV, IOCFG2_GDO2_CFG,
+]};
+pub const IOCFG1_GDO_DS: Field = Field{name: "GDO_DS", mask: 0x80, values: &[]};
+pub const IOCFG1_GDO1_INV: Field = Field{name: "GDO1_INV", mask: 0x40, values: &[]};
+pub const IOCFG1_GDO1_CFG: Field = Field{name: "GDO1_CFG", mask: 0x3F, values: &[]};
+pub const IOCFG1: Register = Register{name: "IOCFG1", sfr: None, xdata: Some(0xDF30), fields: &[
+    IOCFG1_GDO_DS, IOCFG1_GDO1_INV, IOCFG1_GDO1_CFG,
+]};
+pub const IOCFG0_GDO0_INV: Field = Field{name: "GDO0_INV", mask: 0x40, values: &[]};
+pub const IOCFG0_GDO0_CFG: Field = Field{name: "GDO0_CFG", mask: 0x3F, values: &[]};
+pub const IOCFG0: Register = Register{name: "IOCFG0", sfr: None, xdata: Some(0xDF31), fields: &[
+    IOCFG0_GDO0_INV, IOCFG0_GDO0_CFG,
+]};
+pub const PARTNUM: Register = Register{name: "PARTNUM", sfr: None, xdata: Some(0xDF36), fields: &[]};
+pub const VERSION: Register = Register{name: "VERSION", sfr: None, xdata: Some(0xDF37), fields: &[]};
+pub const FREQEST: Register = Register{name: "FREQEST", sfr: None, xdata: Some(0xDF38), fields: &[]};
+pub const LQI_CRC_OK: Field = Field{name: "CRC_OK", mask: 0x80, values: &[]};
+pub const LQI_LQI_EST: Field = Field{name: "LQI_EST", mask: 0x7F, values: &[]};
+pub const LQI: Register = Register{name: "LQI", sfr: None, xdata: Some(0xDF39), fields: &[
+    LQI_CRC_OK, LQI_LQI_EST,
+]};
+pub const RSSI: Register = Register{name: "RSSI", sfr: None, xdata: Some(0xDF3A), fields: &[]};
+pub const MARCSTATE_MARC_STATE: Field = Field{name: "MARC_STATE", mask: 0x1F, values: &[
+    FieldValue{name: "SLEEP", value: 0x00},
+    FieldValue{name: "IDLE", value: 0x01},
+    FieldValue{name: "VCOON_MC", value: 0x03},
+    FieldValue{name: "REGON_MC", value: 0x04},
+    FieldValue{name: "MANCAL", value: 0x05},
+    FieldValue{name: "VCOON", value: 0x06},
+    FieldValue{name: "REGON", value: 0x07},
+    FieldValue{name: "STARTCAL", value: 0x08},
+    FieldValue{name: "BWBOOST", value: 0x09},
+    FieldValue{name: "FS_LOCK", value: 0x0A},
+    FieldValue{name: "IFADCON", value: 0x0B},
+    FieldValue{name: "ENDCAL", value: 0x0C},
+    FieldValue{name: "RX", value: 0x0D},
+    FieldValue{name: "RX_END", value: 0x0E},
+    FieldValue{name: "RX_RST", value: 0x0F},
+    FieldValue{name: "TXRX_SWITCH", value: 0x10},
+    FieldValue{name: "RX_OVERFLOW", value: 0x11},
+    FieldValue{name: "FSTXON", value: 0x12},
+    FieldValue{name: "TX", value: 0x13},
+    FieldValue{name: "TX_END", value: 0x14},
+    FieldValue{name: "RXTX_SWITCH", value: 0x15},
+    FieldValue{name: "TX_UNDERFLOW", value: 0x16},
+]};
+pub const MARCSTATE: Register = Register{name: "MARCSTATE", sfr: None, xdata: Some(0xDF3B), fields: &[
+    MARCSTATE_MARC_STATE,
+]};
+pub const PKTSTATUS_CRC_OK: Field = Field{name: "CRC_OK", mask: 0x80, values: &[]};
+pub const PKTSTATUS_CS: Field = Field{name: "CS", mask: 0x40, values: &[]};
+pub const PKTSTATUS_PQT_REACHED: Field = Field{name: "PQT_REACHED", mask: 0x20, values: &[]};
+pub const PKTSTATUS_CCA: Field = Field{name: "CCA", mask: 0x10, values: &[]};
+pub const PKTSTATUS_SFD: Field = Field{name: "SFD", mask: 0x08, values: &[]};
+pub const PKTSTATUS_GDO2: Field = Field{name: "GDO2", mask: 0x04, values: &[]};
+pub const PKTSTATUS_GDO0: Field = Field{name: "GDO0", mask: 0x01, values: &[]};
+pub const PKTSTATUS: Register = Register{name: "PKTSTATUS", sfr: None, xdata: Some(0xDF3C), fields: &[
+    PKTSTATUS_CRC_OK, PKTSTATUS_CS, PKTSTATUS_PQT_REACHED, PKTSTATUS_CCA, PKTSTATUS_SFD, PKTSTATUS_GDO2, PKTSTATUS_GDO0,
+]};
+pub const VCO_VC_DAC: Register = Register{name: "VCO_VC_DAC", sfr: None, xdata: Some(0xDF3D), fields: &[]};
+
+// I2S, XDATA 0xDF40 - 0xDF48
+pub const I2SCFG0_TXIEN: Field = Field{name: "TXIEN", mask: 0x80, values: &[]};
+pub const I2SCFG0_RXIEN: Field = Field{name: "RXIEN", mask: 0x40, values: &[]};
+pub const I2SCFG0_ULAWE: Field = Field{name: "ULAWE", mask: 0x20, values: &[]};
+pub const I2SCFG0_ULAWC: Field = Field{name: "ULAWC", mask: 0x10, values: &[]};
+pub const I2SCFG0_TXMONO: Field = Field{name: "TXMONO", mask: 0x08, values: &[]};
+pub const I2SCFG0_RXMONO: Field = Field{name: "RXMONO", mask: 0x04, values: &[]};
+pub const I2SCFG0_MASTER: Field = Field{name: "MASTER", mask: 0x02, values: &[]};
+pub const I2SCFG0_ENAB: Field = Field{name: "ENAB", mask: 0x01, values: &[]};
+pub const I2SCFG0: Register = Register{name: "I2SCFG0", sfr: None, xdata: Some(0xDF40), fields: &[
+    I2SCFG0_TXIEN, I2SCFG0_RXIEN, I2SCFG0_ULAWE, I2SCFG0_ULAWC, I2SCFG0_TXMONO, I2SCFG0_RXMONO,
+    I2SCFG0_MASTER, I2SCFG0_ENAB,
+]};
+pub const I2SCFG1_WORDS: Field = Field{name: "WORDS", mask: 0xF8, values: &[]};
+pub const I2SCFG1_TRIGNUM: Field = Field{name: "TRIGNUM", mask: 0x06, values: &[
+    FieldValue{name: "NO_TRIG", value: 0x00},
+    FieldValue{name: "USB_SOF", value: 0x02},
+    FieldValue{name: "IOC_1", value: 0x04},
+    FieldValue{name: "T1_CH0", value: 0x06},
+]};
+pub const I2SCFG1_IOLOC: Field = Field{name: "IOLOC", mask: 0x01, values: &[]};
+pub const I2SCFG1: Register = Register{name: "I2SCFG1", sfr: None, xdata: Some(0xDF41), fields: &[
+    I2SCFG1_WORDS, I2SCFG1_TRIGNUM, I2SCFG1_IOLOC,
+]};
+pub const I2SDATL: Register = Register{name: "I2SDATL", sfr: None, xdata: Some(0xDF42), fields: &[]};
+pub const I2SDATH: Register = Register{name: "I2SDATH", sfr: None, xdata: Some(0xDF43), fields: &[]};
+pub const I2SWCNT: Register = Register{name: "I2SWCNT", sfr: None, xdata: Some(0xDF44), fields: &[]};
+pub const I2SSTAT_TXUNF: Field = Field{name: "TXUNF", mask: 0x80, values: &[]};
+pub const I2SSTAT_RXOVF: Field = Field{name: "RXOVF", mask: 0x40, values: &[]};
+pub const I2SSTAT_TXLR: Field = Field{name: "TXLR", mask: 0x20, values: &[]};
+pub const I2SSTAT_RXLR: Field = Field{name: "RXLR", mask: 0x10, values: &[]};
+pub const I2SSTAT_TXIRQ: Field = Field{name: "TXIRQ", mask: 0x08, values: &[]};
+pub const I2SSTAT_RXIRQ: Field = Field{name: "RXIRQ", mask: 0x04, values: &[]};
+pub const I2SSTAT_WCNT_10BIT: Field = Field{name: "WCNT_10BIT", mask: 0x02, values: &[]};
+pub const I2SSTAT_WCNT_9_10BIT: Field = Field{name: "WCNT_9_10BIT", mask: 0x02, values: &[]};
+pub const I2SSTAT_WCNT_9BIT: Field = Field{name: "WCNT_9BIT", mask: 0x01, values: &[]};
+pub const I2SSTAT: Register = Register{name: "I2SSTAT", sfr: None, xdata: Some(0xDF45), fields: &[
+    I2SSTAT_TXUNF, I2SSTAT_RXOVF, I2SSTAT_TXLR, I2SSTAT_RXLR, I2SSTAT_TXIRQ, I2SSTAT_RXIRQ,
+    I2SSTAT_WCNT_10BIT, I2SSTAT_WCNT_9_10BIT, I2SSTAT_WCNT_9BIT,
+]};
+pub const I2SCLKF0: Register = Register{name: "I2SCLKF0", sfr: None, xdata: Some(0xDF46), fields: &[]};
+pub const I2SCLKF1: Register = Register{name: "I2SCLKF1", sfr: None, xdata: Some(0xDF47), fields: &[]};
+pub const I2SCLKF2: Register = Register{name: "I2SCLKF2", sfr: None, xdata: Some(0xDF48), fields: &[]};
+
+// SFRs (the ones with an XDATA address can be peeked and poked there)
+pub const P0_7: Field = Field{name: "P0_7", mask: 0x80, values: &[]};
+pub const P0_6: Field = Field{name: "P0_6", mask: 0x40, values: &[]};
+pub const P0_5: Field = Field{name: "P0_5", mask: 0x20, values: &[]};
+pub const P0_4: Field = Field{name: "P0_4", mask: 0x10, values: &[]};
+pub const P0_3: Field = Field{name: "P0_3", mask: 0x08, values: &[]};
+pub const P0_2: Field = Field{name: "P0_2", mask: 0x04, values: &[]};
+pub const P0_1: Field = Field{name: "P0_1", mask: 0x02, values: &[]};
+pub const P0_0: Field = Field{name: "P0_0", mask: 0x01, values: &[]};
+pub const P0: Register = Register{name: "P0", sfr: Some(0x80), xdata: None, fields: &[
+    P0_7, P0_6, P0_5, P0_4, P0_3, P0_2, P0_1, P0_0,
+]};
+pub const SP: Register = Register{name: "SP", sfr: Some(0x81), xdata: None, fields: &[]};
+pub const DPL0: Register = Register{name: "DPL0", sfr: Some(0x82), xdata: None, fields: &[]};
+pub const DPH0: Register = Register{name: "DPH0", sfr: Some(0x83), xdata: None, fields: &[]};
+pub const DPL1: Register = Register{name: "DPL1", sfr: Some(0x84), xdata: None, fields: &[]};
+pub const DPH1: Register = Register{name: "DPH1", sfr: Some(0x85), xdata: None, fields: &[]};
+pub const U0CSR_MODE: Field = Field{name: "MODE", mask: 0x80, values: &[]};
+pub const U0CSR_RE: Field = Field{name: "RE", mask: 0x40, values: &[]};
+pub const U0CSR_SLAVE: Field = Field{name: "SLAVE", mask: 0x20, values: &[]};
+pub const U0CSR_FE: Field = Field{name: "FE", mask: 0x10, values: &[]};
+pub const U0CSR_ERR: Field = Field{name: "ERR", mask: 0x08, values: &[]};
+pub const U0CSR_RX_BYTE: Field = Field{name: "RX_BYTE", mask: 0x04, values: &[]};
+pub const U0CSR_TX_BYTE: Field = Field{name: "TX_BYTE", mask: 0x02, values: &[]};
+pub const U0CSR_ACTIVE: Field = Field{name: "ACTIVE", mask: 0x01, values: &[]};
+pub const U0CSR: Register = Register{name: "U0CSR", sfr: Some(0x86), xdata: Some(0xDF86), fields: &[
+    U0CSR_MODE, U0CSR_RE, U0CSR_SLAVE, U0CSR_FE, U0CSR_ERR, U0CSR_RX_BYTE, U0CSR_TX_BYTE,
+    U0CSR_ACTIVE,
+]};
+pub const PCON_IDLE: Field = Field{name: "IDLE", mask: 0x01, values: &[]};
+pub const PCON: Register = Register{name: "PCON", sfr: Some(0x87), xdata: None, fields: &[
+    PCON_IDLE,
+]};
+pub const TCON_URX1IF: Field = Field{name: "URX1IF", mask: 0x80, values: &[]};
+pub const TCON_ADCIF: Field = Field{name: "ADCIF", mask: 0x20, values: &[]};
+pub const TCON_URX0IF: Field = Field{name: "URX0IF", mask: 0x08, values: &[]};
+pub const TCON_IT1: Field = Field{name: "IT1", mask: 0x04, values: &[]};
+pub const TCON_RFTXRXIF: Field = Field{name: "RFTXRXIF", mask: 0x02, values: &[]};
+pub const TCON_IT0: Field = Field{name: "IT0", mask: 0x01, values: &[]};
+pub const TCON: Register = Register{name: "TCON", sfr: Some(0x88), xdata: None, fields: &[
+    TCON_URX1IF, TCON_ADCIF, TCON_URX0IF, TCON_IT1, TCON_RFTXRXIF, TCON_IT0,
+]};
+pub const P0IFG_USB_RESUME: Field = Field{name: "USB_RESUME", mask: 0x80, values: &[]};
+pub const P0IFG: Register = Register{name: "P0IFG", sfr: Some(0x89), xdata: Some(0xDF89), fields: &[
+    P0IFG_USB_RESUME,
+]};
+pub const P1IFG: Register = Register{name: "P1IFG", sfr: Some(0x8A), xdata: Some(0xDF8A), fields: &[]};
+pub const P2IFG: Register = Register{name: "P2IFG", sfr: Some(0x8B), xdata: Some(0xDF8B), fields: &[]};
+pub const PICTL_PADSC: Field = Field{name: "PADSC", mask: 0x40, values: &[]};
+pub const PICTL_P2IEN: Field = Field{name: "P2IEN", mask: 0x20, values: &[]};
+pub const PICTL_P0IENH: Field = Field{name: "P0IENH", mask: 0x10, values: &[]};
+pub const PICTL_P0IENL: Field = Field{name: "P0IENL", mask: 0x08, values: &[]};
+pub const PICTL_P2ICON: Field = Field{name: "P2ICON", mask: 0x04, values: &[]};
+pub const PICTL_P1ICON: Field = Field{name: "P1ICON", mask: 0x02, values: &[]};
+pub const PICTL_P0ICON: Field = Field{name: "P0ICON", mask: 0x01, values: &[]};
+pub const PICTL: Register = Register{name: "PICTL", sfr: Some(0x8C), xdata: Some(0xDF8C), fields: &[
+    PICTL_PADSC, PICTL_P2IEN, PICTL_P0IENH, PICTL_P0IENL, PICTL_P2ICON, PICTL_P1ICON, PICTL_P0ICON,
+]};
+pub const P1IEN: Register = Register{name: "P1IEN", sfr: Some(0x8D), xdata: Some(0xDF8D), fields: &[]};
+pub const P0INP: Register = Register{name: "P0INP", sfr: Some(0x8F), xdata: Some(0xDF8F), fields: &[]};
+pub const P1_7: Field = Field{name: "P1_7", mask: 0x80, values: &[]};
+pub const P1_6: Field = Field{name: "P1_6", mask: 0x40, values: &[]};
+pub const P1_5: Field = Field{name: "P1_5", mask: 0x20, values: &[]};
+pub const P1_4: Field = Field{name: "P1_4", mask: 0x10, values: &[]};
+pub const P1_3: Field = Field{name: "P1_3", mask: 0x08, values: &[]};
+pub const P1_2: Field = Field{name: "P1_2", mask: 0x04, values: &[]};
+pub const P1_1: Field = Field{name: "P1_1", mask: 0x02, values: &[]};
+pub const P1_0: Field = Field{name: "P1_0", mask: 0x01, values: &[]};
+pub const P1: Register = Register{name: "P1", sfr: Some(0x90), xdata: None, fields: &[
+    P1_7, P1_6, P1_5, P1_4, P1_3, P1_2, P1_1, P1_0,
+]};
+pub const RFIM_IM_TXUNF: Field = Field{name: "IM_TXUNF", mask: 0x80, values: &[]};
+pub const RFIM_IM_RXOVF: Field = Field{name: "IM_RXOVF", mask: 0x40, values: &[]};
+pub const RFIM_IM_TIMEOUT: Field = Field{name: "IM_TIMEOUT", mask: 0x20, values: &[]};
+pub const RFIM_IM_DONE: Field = Field{name: "IM_DONE", mask: 0x10, values: &[]};
+pub const RFIM_IM_CS: Field = Field{name: "IM_CS", mask: 0x08, values: &[]};
+pub const RFIM_IM_PQT: Field = Field{name: "IM_PQT", mask: 0x04, values: &[]};
+pub const RFIM_IM_CCA: Field = Field{name: "IM_CCA", mask: 0x02, values: &[]};
+pub const RFIM_IM_SFD: Field = Field{name: "IM_SFD", mask: 0x01, values: &[]};
+pub const RFIM: Register = Register{name: "RFIM", sfr: Some(0x91), xdata: Some(0xDF91), fields: &[
+    RFIM_IM_TXUNF, RFIM_IM_RXOVF, RFIM_IM_TIMEOUT, RFIM_IM_DONE, RFIM_IM_CS, RFIM_IM_PQT,
+    RFIM_IM_CCA, RFIM_IM_SFD,
+]};
+pub const DPS_VDPS: Field = Field{name: "VDPS", mask: 0x01, values: &[]};
+pub const DPS: Register = Register{name: "DPS", sfr: Some(0x92), xdata: None, fields: &[
+    DPS_VDPS,
+]};
+pub const MPAGE: Register = Register{name: "MPAGE", sfr: Some(0x93), xdata: Some(0xDF93), fields: &[]};
+pub const S0CON: Register = Register{name: "S0CON", sfr: Some(0x98), xdata: None, fields: &[]};
+pub const IEN2_WDTIE: Field = Field{name: "WDTIE", mask: 0x20, values: &[]};
+pub const IEN2_P1IE: Field = Field{name: "P1IE", mask: 0x10, values: &[]};
+pub const IEN2_I2STXIE: Field = Field{name: "I2STXIE", mask: 0x08, values: &[]};
+pub const IEN2_UTX1IE: Field = Field{name: "UTX1IE", mask: 0x08, values: &[]};
+pub const IEN2_UTX0IE: Field = Field{name: "UTX0IE", mask: 0x04, values: &[]};
+pub const IEN2_P2IE: Field = Field{name: "P2IE", mask: 0x02, values: &[]};
+pub const IEN2_USBIE: Field = Field{name: "USBIE", mask: 0x02, values: &[]};
+pub const IEN2_RFIE: Field = Field{name: "RFIE", mask: 0x01, values: &[]};
+pub const IEN2: Register = Register{name: "IEN2", sfr: Some(0x9A), xdata: None, fields: &[
+    IEN2_WDTIE, IEN2_P1IE, IEN2_I2STXIE, IEN2_UTX1IE, IEN2_UTX0IE, IEN2_P2IE, IEN2_USBIE, IEN2_RFIE,
+]};
+pub const S1CON_RFIF_1: Field = Field{name: "RFIF_1", mask: 0x02, values: &[]};
+pub const S1CON_RFIF_0: Field = Field{name: "RFIF_0", mask: 0x01, values: &[]};
+pub const S1CON: Register = Register{name: "S1CON", sfr: Some(0x9B), xdata: None, fields: &[
+    S1CON_RFIF_1, S1CON_RFIF_0,
+]};
+pub const T2CT: Register = Register{name: "T2CT", sfr: Some(0x9C), xdata: Some(0xDF9C), fields: &[]};
+pub const T2PR: Register = Register{name: "T2PR", sfr: Some(0x9D), xdata: Some(0xDF9D), fields: &[]};
+pub const T2CTL_TEX: Field = Field{name: "TEX", mask: 0x40, values: &[]};
+pub const T2CTL_INT: Field = Field{name: "INT", mask: 0x10, values: &[]};
+pub const T2CTL_TIG: Field = Field{name: "TIG", mask: 0x04, values: &[]};
+pub const T2CTL_TIP: Field = Field{name: "TIP", mask: 0x03, values: &[
+    FieldValue{name: "64", value: 0x00},
+    FieldValue{name: "128", value: 0x01},
+    FieldValue{name: "256", value: 0x02},
+    FieldValue{name: "1024", value: 0x03},
+]};
+pub const T2CTL: Register = Register{name: "T2CTL", sfr: Some(0x9E), xdata: Some(0xDF9E), fields: &[
+    T2CTL_TEX, T2CTL_INT, T2CTL_TIG, T2CTL_TIP,
+]};
+pub const P2_7: Field = Field{name: "P2_7", mask: 0x80, values: &[]};
+pub const P2_6: Field = Field{name: "P2_6", mask: 0x40, values: &[]};
+pub const P2_5: Field = Field{name: "P2_5", mask: 0x20, values: &[]};
+pub const P2_4: Field = Field{name: "P2_4", mask: 0x10, values: &[]};
+pub const P2_3: Field = Field{name: "P2_3", mask: 0x08, values: &[]};
+pub const P2_2: Field = Field{name: "P2_2", mask: 0x04, values: &[]};
+pub const P2_1: Field = Field{name: "P2_1", mask: 0x02, values: &[]};
+pub const P2_0: Field = Field{name: "P2_0", mask: 0x01, values: &[]};
+pub const P2: Register = Register{name: "P2", sfr: Some(0xA0), xdata: None, fields: &[
+    P2_7, P2_6, P2_5, P2_4, P2_3, P2_2, P2_1, P2_0,
+]};
+pub const WORIRQ_EVENT0_MASK: Field = Field{name: "EVENT0_MASK", mask: 0x10, values: &[]};
+pub const WORIRQ_EVENT0_FLAG: Field = Field{name: "EVENT0_FLAG", mask: 0x01, values: &[]};
+pub const WORIRQ: Register = Register{name: "WORIRQ", sfr: Some(0xA1), xdata: Some(0xDFA1), fields: &[
+    WORIRQ_EVENT0_MASK, WORIRQ_EVENT0_FLAG,
+]};
+pub const WORCTRL_WOR_RESET: Field = Field{name: "WOR_RESET", mask: 0x04, values: &[]};
+pub const WORCTRL_WOR_RES: Field = Field{name: "WOR_RES", mask: 0x03, values: &[
+    FieldValue{name: "1", value: 0x00},
+    FieldValue{name: "32", value: 0x01},
+    FieldValue{name: "1024", value: 0x02},
+    FieldValue{name: "32768", value: 0x03},
+]};
+pub const WORCTRL: Register = Register{name: "WORCTRL", sfr: Some(0xA2), xdata: Some(0xDFA2), fields: &[
+    WORCTRL_WOR_RESET, WORCTRL_WOR_RES,
+]};
+pub const WOREVT0: Register = Register{name: "WOREVT0", sfr: Some(0xA3), xdata: Some(0xDFA3), fields: &[]};
+pub const WOREVT1: Register = Register{name: "WOREVT1", sfr: Some(0xA4), xdata: Some(0xDFA4), fields: &[]};
+pub const WORTIME0: Register = Register{name: "WORTIME0", sfr: Some(0xA5), xdata: Some(0xDFA5), fields: &[]};
+pub const WORTIME1: Register = Register{name: "WORTIME1", sfr: Some(0xA6), xdata: Some(0xDFA6), fields: &[]};
+pub const IEN0_EA: Field = Field{name: "EA", mask: 0x80, values: &[]};
+pub const IEN0_STIE: Field = Field{name: "STIE", mask: 0x20, values: &[]};
+pub const IEN0_ENCIE: Field = Field{name: "ENCIE", mask: 0x10, values: &[]};
+pub const IEN0_URX1IE: Field = Field{name: "URX1IE", mask: 0x08, values: &[]};
+pub const IEN0_URX0IE: Field = Field{name: "URX0IE", mask: 0x04, values: &[]};
+pub const IEN0_ADCIE: Field = Field{name: "ADCIE", mask: 0x02, values: &[]};
+pub const IEN0_RFTXRXIE: Field = Field{name: "RFTXRXIE", mask: 0x01, values: &[]};
+pub const IEN0: Register = Register{name: "IEN0", sfr: Some(0xA8), xdata: None, fields: &[
+    IEN0_EA, IEN0_STIE, IEN0_ENCIE, IEN0_URX1IE, IEN0_URX0IE, IEN0_ADCIE, IEN0_RFTXRXIE,
+]};
+pub const IP0_IPG5: Field = Field{name: "IPG5", mask: 0x20, values: &[]};
+pub const IP0_IPG4: Field = Field{name: "IPG4", mask: 0x10, values: &[]};
+pub const IP0_IPG3: Field = Field{name: "IPG3", mask: 0x08, values: &[]};
+pub const IP0_IPG2: Field = Field{name: "IPG2", mask: 0x04, values: &[]};
+pub const IP0_IPG1: Field = Field{name: "IPG1", mask: 0x02, values: &[]};
+pub const IP0_IPG0: Field = Field{name: "IPG0", mask: 0x01, values: &[]};
+pub const IP0: Register = Register{name: "IP0", sfr: Some(0xA9), xdata: None, fields: &[
+    IP0_IPG5, IP0_IPG4, IP0_IPG3, IP0_IPG2, IP0_IPG1, IP0_IPG0,
+]};
+pub const FWT: Register = Register{name: "FWT", sfr: Some(0xAB), xdata: Some(0xDFAB), fields: &[]};
+pub const FADDRL: Register = Register{name: "FADDRL", sfr: Some(0xAC), xdata: Some(0xDFAC), fields: &[]};
+pub const FADDRH: Register = Register{name: "FADDRH", sfr: Some(0xAD), xdata: Some(0xDFAD), fields: &[]};
+pub const FCTL_BUSY: Field = Field{name: "BUSY", mask: 0x80, values: &[]};
+pub const FCTL_SWBSY: Field = Field{name: "SWBSY", mask: 0x40, values: &[]};
+pub const FCTL_CONTRD: Field = Field{name: "CONTRD", mask: 0x10, values: &[]};
+pub const FCTL_WRITE: Field = Field{name: "WRITE", mask: 0x02, values: &[]};
+pub const FCTL_ERASE: Field = Field{name: "ERASE", mask: 0x01, values: &[]};
+pub const FCTL: Register = Register{name: "FCTL", sfr: Some(0xAE), xdata: Some(0xDFAE), fields: &[
+    FCTL_BUSY, FCTL_SWBSY, FCTL_CONTRD, FCTL_WRITE, FCTL_ERASE,
+]};
+pub const FWDATA: Register = Register{name: "FWDATA", sfr: Some(0xAF), xdata: Some(0xDFAF), fields: &[]};
+pub const ENCDI: Register = Register{name: "ENCDI", sfr: Some(0xB1), xdata: Some(0xDFB1), fields: &[]};
+pub const ENCDO: Register = Register{name: "ENCDO", sfr: Some(0xB2), xdata: Some(0xDFB2), fields: &[]};
+pub const ENCCS_MODE: Field = Field{name: "MODE", mask: 0x70, values: &[
+    FieldValue{name: "CBC", value: 0x00},
+    FieldValue{name: "CFB", value: 0x10},
+    FieldValue{name: "OFB", value: 0x20},
+    FieldValue{name: "CTR", value: 0x30},
+    FieldValue{name: "ECB", value: 0x40},
+    FieldValue{name: "CBCMAC", value: 0x50},
+]};
+pub const ENCCS_RDY: Field = Field{name: "RDY", mask: 0x08, values: &[]};
+pub const ENCCS_CMD: Field = Field{name: "CMD", mask: 0x06, values: &[
+    FieldValue{name: "ENC", value: 0x00},
+    FieldValue{name: "DEC", value: 0x02},
+    FieldValue{name: "LDKEY", value: 0x04},
+    FieldValue{name: "LDIV", value: 0x06},
+]};
+pub const ENCCS_ST: Field = Field{name: "ST", mask: 0x01, values: &[]};
+pub const ENCCS: Register = Register{name: "ENCCS", sfr: Some(0xB3), xdata: Some(0xDFB3), fields: &[
+    ENCCS_MODE, ENCCS_RDY, ENCCS_CMD, ENCCS_ST,
+]};
+pub const ADCCON1_EOC: Field = Field{name: "EOC", mask: 0x80, values: &[]};
+pub const ADCCON1_ST: Field = Field{name: "ST", mask: 0x40, values: &[]};
+pub const ADCCON1_STSEL: Field = Field{name: "STSEL", mask: 0x30, values: &[
+    FieldValue{name: "P2_0", value: 0x00},
+    FieldValue{name: "FULL_SPEED", value: 0x10},
+    FieldValue{name: "T1C0_CMP_EVT", value: 0x20},
+    FieldValue{name: "ST", value: 0x30},
+]};
+pub const ADCCON1_RCTRL: Field = Field{name: "RCTRL", mask: 0x0C, values: &[
+    FieldValue{name: "COMPL", value: 0x00},
+    FieldValue{name: "LFSR13", value: 0x04},
+]};
+pub const ADCCON1: Register = Register{name: "ADCCON1", sfr: Some(0xB4), xdata: Some(0xDFB4), fields: &[
+    ADCCON1_EOC, ADCCON1_ST, ADCCON1_STSEL, ADCCON1_RCTRL,
+]};
+pub const ADCCON2_SREF: Field = Field{name: "SREF", mask: 0xC0, values: &[
+    FieldValue{name: "1_25V", value: 0x00},
+    FieldValue{name: "P0_7", value: 0x40},
+    FieldValue{name: "AVDD", value: 0x80},
+    FieldValue{name: "P0_6_P0_7", value: 0xC0},
+]};
+pub const ADCCON2_SDIV: Field = Field{name: "SDIV", mask: 0x30, values: &[
+    FieldValue{name: "64", value: 0x00},
+    FieldValue{name: "128", value: 0x10},
+    FieldValue{name: "256", value: 0x20},
+    FieldValue{name: "512", value: 0x30},
+]};
+pub const ADCCON2_SCH: Field = Field{name: "SCH", mask: 0x0F, values: &[
+    FieldValue{name: "AIN0", value: 0x00},
+    FieldValue{name: "AIN1", value: 0x01},
+    FieldValue{name: "AIN2", value: 0x02},
+    FieldValue{name: "AIN3", value: 0x03},
+    FieldValue{name: "AIN4", value: 0x04},
+    FieldValue{name: "AIN5", value: 0x05},
+    FieldValue{name: "AIN6", value: 0x06},
+    FieldValue{name: "AIN7", value: 0x07},
+    FieldValue{name: "AIN0_1", value: 0x08},
+    FieldValue{name: "AIN2_3", value: 0x09},
+    FieldValue{name: "AIN4_5", value: 0x0A},
+    FieldValue{name: "AIN6_7", value: 0x0B},
+    FieldValue{name: "GND", value: 0x0C},
+    FieldValue{name: "POSVOL", value: 0x0D},
+    FieldValue{name: "TEMPR", value: 0x0E},
+    FieldValue{name: "VDD_3", value: 0x0F},
+]};
+pub const ADCCON2: Register = Register{name: "ADCCON2", sfr: Some(0xB5), xdata: Some(0xDFB5), fields: &[
+    ADCCON2_SREF, ADCCON2_SDIV, ADCCON2_SCH,
+]};
+pub const ADCCON3_EREF: Field = Field{name: "EREF", mask: 0xC0, values: &[
+    FieldValue{name: "1_25V", value: 0x00},
+    FieldValue{name: "P0_7", value: 0x40},
+    FieldValue{name: "AVDD", value: 0x80},
+    FieldValue{name: "P0_6_P0_7", value: 0xC0},
+]};
+pub const ADCCON3_EDIV: Field = Field{name: "EDIV", mask: 0x30, values: &[
+    FieldValue{name: "64", value: 0x00},
+    FieldValue{name: "128", value: 0x10},
+    FieldValue{name: "256", value: 0x20},
+    FieldValue{name: "512", value: 0x30},
+]};
+pub const ADCCON3_ECH: Field = Field{name: "ECH", mask: 0x0F, values: &[
+    FieldValue{name: "AIN0", value: 0x00},
+    FieldValue{name: "AIN1", value: 0x01},
+    FieldValue{name: "AIN2", value: 0x02},
+    FieldValue{name: "AIN3", value: 0x03},
+    FieldValue{name: "AIN4", value: 0x04},
+    FieldValue{name: "AIN5", value: 0x05},
+    FieldValue{name: "AIN6", value: 0x06},
+    FieldValue{name: "AIN7", value: 0x07},
+    FieldValue{name: "AIN0_1", value: 0x08},
+    FieldValue{name: "AIN2_3", value: 0x09},
+    FieldValue{name: "AIN4_5", value: 0x0A},
+    FieldValue{name: "AIN6_7", value: 0x0B},
+    FieldValue{name: "GND", value: 0x0C},
+    FieldValue{name: "POSVOL", value: 0x0D},
+    FieldValue{name: "TEMPR", value: 0x0E},
+    FieldValue{name: "VDD_3", value: 0x0F},
+]};
+pub const ADCCON3: Register = Register{name: "ADCCON3", sfr: Some(0xB6), xdata: Some(0xDFB6), fields: &[
+    ADCCON3_EREF, ADCCON3_EDIV, ADCCON3_ECH,
+]};
+pub const IEN1_P0IE: Field = Field{name: "P0IE", mask: 0x20, values: &[]};
+pub const IEN1_T4IE: Field = Field{name: "T4IE", mask: 0x10, values: &[]};
+pub const IEN1_T3IE: Field = Field{name: "T3IE", mask: 0x08, values: &[]};
+pub const IEN1_T2IE: Field = Field{name: "T2IE", mask: 0x04, values: &[]};
+pub const IEN1_T1IE: Field = Field{name: "T1IE", mask: 0x02, values: &[]};
+pub const IEN1_DMAIE: Field = Field{name: "DMAIE", mask: 0x01, values: &[]};
+pub const IEN1: Register = Register{name: "IEN1", sfr: Some(0xB8), xdata: None, fields: &[
+    IEN1_P0IE, IEN1_T4IE, IEN1_T3IE, IEN1_T2IE, IEN1_T1IE, IEN1_DMAIE,
+]};
+pub const IP1_IPG5: Field = Field{name: "IPG5", mask: 0x20, values: &[]};
+pub const IP1_IPG4: Field = Field{name: "IPG4", mask: 0x10, values: &[]};
+pub const IP1_IPG3: Field = Field{name: "IPG3", mask: 0x08, values: &[]};
+pub const IP1_IPG2: Field = Field{name: "IPG2", mask: 0x04, values: &[]};
+pub const IP1_IPG1: Field = Field{name: "IPG1", mask: 0x02, values: &[]};
+pub const IP1_IPG0: Field = Field{name: "IPG0", mask: 0x01, values: &[]};
+pub const IP1: Register = Register{name: "IP1", sfr: Some(0xB9), xdata: None, fields: &[
+    IP1_IPG5, IP1_IPG4, IP1_IPG3, IP1_IPG2, IP1_IPG1, IP1_IPG0,
+]};
+pub const ADCL: Register = Register{name: "ADCL", sfr: Some(0xBA), xdata: Some(0xDFBA), fields: &[]};
+pub const ADCH: Register = Register{name: "ADCH", sfr: Some(0xBB), xdata: Some(0xDFBB), fields: &[]};
+pub const RNDL: Register = Register{name: "RNDL", sfr: Some(0xBC), xdata: Some(0xDFBC), fields: &[]};
+pub const RNDH: Register = Register{name: "RNDH", sfr: Some(0xBD), xdata: Some(0xDFBD), fields: &[]};
+pub const SLEEP_USB_EN: Field = Field{name: "USB_EN", mask: 0x80, values: &[]};
+pub const SLEEP_XOSC_S: Field = Field{name: "XOSC_S", mask: 0x40, values: &[]};
+pub const SLEEP_HFRC_S: Field = Field{name: "HFRC_S", mask: 0x20, values: &[]};
+pub const SLEEP_RST: Field = Field{name: "RST", mask: 0x18, values: &[
+    FieldValue{name: "POR_BOD", value: 0x00},
+    FieldValue{name: "EXT", value: 0x08},
+    FieldValue{name: "WDT", value: 0x10},
+]};
+pub const SLEEP_OSC_PD: Field = Field{name: "OSC_PD", mask: 0x04, values: &[]};
+pub const SLEEP_MODE: Field = Field{name: "MODE", mask: 0x03, values: &[
+    FieldValue{name: "PM0", value: 0x00},
+    FieldValue{name: "PM1", value: 0x01},
+    FieldValue{name: "PM2", value: 0x02},
+    FieldValue{name: "PM3", value: 0x03},
+]};
+pub const SLEEP: Register = Register{name: "SLEEP", sfr: Some(0xBE), xdata: Some(0xDFBE), fields: &[
+    SLEEP_USB_EN, SLEEP_XOSC_S, SLEEP_HFRC_S, SLEEP_RST, SLEEP_OSC_PD, SLEEP_MODE,
+]};
+pub const IRCON_STIF: Field = Field{name: "STIF", mask: 0x80, values: &[]};
+pub const IRCON_P0IF: Field = Field{name: "P0IF", mask: 0x20, values: &[]};
+pub const IRCON_T4IF: Field = Field{name: "T4IF", mask: 0x10, values: &[]};
+pub const IRCON_T3IF: Field = Field{name: "T3IF", mask: 0x08, values: &[]};
+pub const IRCON_T2IF: Field = Field{name: "T2IF", mask: 0x04, values: &[]};
+pub const IRCON_T1IF: Field = Field{name: "T1IF", mask: 0x02, values: &[]};
+pub const IRCON_DMAIF: Field = Field{name: "DMAIF", mask: 0x01, values: &[]};
+pub const IRCON: Register = Register{name: "IRCON", sfr: Some(0xC0), xdata: None, fields: &[
+    IRCON_STIF, IRCON_P0IF, IRCON_T4IF, IRCON_T3IF, IRCON_T2IF, IRCON_T1IF, IRCON_DMAIF,
+]};
+pub const U0DBUF: Register = Register{name: "U0DBUF", sfr: Some(0xC1), xdata: Some(0xDFC1), fields: &[]};
+pub const U0BAUD: Register = Register{name: "U0BAUD", sfr: Some(0xC2), xdata: Some(0xDFC2), fields: &[]};
+pub const U0UCR_FLUSH: Field = Field{name: "FLUSH", mask: 0x80, values: &[]};
+pub const U0UCR_FLOW: Field = Field{name: "FLOW", mask: 0x40, values: &[]};
+pub const U0UCR_D9: Field = Field{name: "D9", mask: 0x20, values: &[]};
+pub const U0UCR_BIT9: Field = Field{name: "BIT9", mask: 0x10, values: &[]};
+pub const U0UCR_PARITY: Field = Field{name: "PARITY", mask: 0x08, values: &[]};
+pub const U0UCR_SPB: Field = Field{name: "SPB", mask: 0x04, values: &[]};
+pub const U0UCR_STOP: Field = Field{name: "STOP", mask: 0x02, values: &[]};
+pub const U0UCR_START: Field = Field{name: "START", mask: 0x01, values: &[]};
+pub const U0UCR: Register = Register{name: "U0UCR", sfr: Some(0xC4), xdata: Some(0xDFC4), fields: &[
+    U0UCR_FLUSH, U0UCR_FLOW, U0UCR_D9, U0UCR_BIT9, U0UCR_PARITY, U0UCR_SPB, U0UCR_STOP, U0UCR_START,
+]};
+pub const U0GCR_CPOL: Field = Field{name: "CPOL", mask: 0x80, values: &[]};
+pub const U0GCR_CPHA: Field = Field{name: "CPHA", mask: 0x40, values: &[]};
+pub const U0GCR_ORDER: Field = Field{name: "ORDER", mask: 0x20, values: &[]};
+pub const U0GCR_BAUD_E: Field = Field{name: "BAUD_E", mask: 0x1F, values: &[]};
+pub const U0GCR: Register = Register{name: "U0GCR", sfr: Some(0xC5), xdata: Some(0xDFC5), fields: &[
+    U0GCR_CPOL, U0GCR_CPHA, U0GCR_ORDER, U0GCR_BAUD_E,
+]};
+pub const CLKCON_OSC32: Field = Field{name: "OSC32", mask: 0x80, values: &[]};
+pub const CLKCON_OSC: Field = Field{name: "OSC", mask: 0x40, values: &[]};
+pub const CLKCON_TICKSPD: Field = Field{name: "TICKSPD", mask: 0x38, values: &[
+    FieldValue{name: "DIV_1", value: 0x00},
+    FieldValue{name: "DIV_2", value: 0x08},
+    FieldValue{name: "DIV_4", value: 0x10},
+    FieldValue{name: "DIV_8", value: 0x18},
+    FieldValue{name: "DIV_16", value: 0x20},
+    FieldValue{name: "DIV_32", value: 0x28},
+    FieldValue{name: "DIV_64", value: 0x30},
+    FieldValue{name: "DIV_128", value: 0x38},
+]};
+pub const CLKCON_CLKSPD: Field = Field{name: "CLKSPD", mask: 0x07, values: &[
+    FieldValue{name: "DIV_1", value: 0x00},
+    FieldValue{name: "DIV_2", value: 0x01},
+    FieldValue{name: "DIV_4", value: 0x02},
+    FieldValue{name: "DIV_8", value: 0x03},
+    FieldValue{name: "DIV_16", value: 0x04},
+    FieldValue{name: "DIV_32", value: 0x05},
+    FieldValue{name: "DIV_64", value: 0x06},
+    FieldValue{name: "DIV_128", value: 0x07},
+]};
+pub const CLKCON: Register = Register{name: "CLKCON", sfr: Some(0xC6), xdata: Some(0xDFC6), fields: &[
+    CLKCON_OSC32, CLKCON_OSC, CLKCON_TICKSPD, CLKCON_CLKSPD,
+]};
+pub const MEMCTR_CACHD: Field = Field{name: "CACHD", mask: 0x02, values: &[]};
+pub const MEMCTR_PREFD: Field = Field{name: "PREFD", mask: 0x01, values: &[]};
+pub const MEMCTR: Register = Register{name: "MEMCTR", sfr: Some(0xC7), xdata: Some(0xDFC7), fields: &[
+    MEMCTR_CACHD, MEMCTR_PREFD,
+]};
+pub const WDCTL_CLR: Field = Field{name: "CLR", mask: 0xF0, values: &[]};
+pub const WDCTL_EN: Field = Field{name: "EN", mask: 0x08, values: &[]};
+pub const WDCTL_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const WDCTL_INT: Field = Field{name: "INT", mask: 0x03, values: &[
+    FieldValue{name: "SEC_1", value: 0x00},
+    FieldValue{name: "MSEC_250", value: 0x01},
+    FieldValue{name: "MSEC_15", value: 0x02},
+    FieldValue{name: "MSEC_2", value: 0x03},
+]};
+pub const WDCTL: Register = Register{name: "WDCTL", sfr: Some(0xC9), xdata: Some(0xDFC9), fields: &[
+    WDCTL_CLR, WDCTL_EN, WDCTL_MODE, WDCTL_INT,
+]};
+pub const T3CNT: Register = Register{name: "T3CNT", sfr: Some(0xCA), xdata: Some(0xDFCA), fields: &[]};
+pub const T3CTL_DIV: Field = Field{name: "DIV", mask: 0xE0, values: &[
+    FieldValue{name: "1", value: 0x00},
+    FieldValue{name: "2", value: 0x20},
+    FieldValue{name: "4", value: 0x40},
+    FieldValue{name: "8", value: 0x60},
+    FieldValue{name: "16", value: 0x80},
+    FieldValue{name: "32", value: 0xA0},
+    FieldValue{name: "64", value: 0xC0},
+    FieldValue{name: "128", value: 0xE0},
+]};
+pub const T3CTL_START: Field = Field{name: "START", mask: 0x10, values: &[]};
+pub const T3CTL_OVFIM: Field = Field{name: "OVFIM", mask: 0x08, values: &[]};
+pub const T3CTL_CLR: Field = Field{name: "CLR", mask: 0x04, values: &[]};
+pub const T3CTL_MODE: Field = Field{name: "MODE", mask: 0x03, values: &[
+    FieldValue{name: "FREERUN", value: 0x00},
+    FieldValue{name: "DOWN", value: 0x01},
+    FieldValue{name: "MODULO", value: 0x02},
+    FieldValue{name: "UPDOWN", value: 0x03},
+]};
+pub const T3CTL: Register = Register{name: "T3CTL", sfr: Some(0xCB), xdata: Some(0xDFCB), fields: &[
+    T3CTL_DIV, T3CTL_START, T3CTL_OVFIM, T3CTL_CLR, T3CTL_MODE,
+]};
+pub const T3CCTL0_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T3CCTL0_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+    FieldValue{name: "SET_CMP_CLR_255", value: 0x28},
+    FieldValue{name: "CLR_CMP_SET_0", value: 0x30},
+]};
+pub const T3CCTL0_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T3CCTL0: Register = Register{name: "T3CCTL0", sfr: Some(0xCC), xdata: Some(0xDFCC), fields: &[
+    T3CCTL0_IM, T3CCTL0_CMP, T3CCTL0_MODE,
+]};
+pub const T3CC0: Register = Register{name: "T3CC0", sfr: Some(0xCD), xdata: Some(0xDFCD), fields: &[]};
+pub const T3CCTL1_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T3CCTL1_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+    FieldValue{name: "SET_CMP_CLR_C0", value: 0x28},
+    FieldValue{name: "CLR_CMP_SET_C0", value: 0x30},
+]};
+pub const T3CCTL1_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T3CCTL1: Register = Register{name: "T3CCTL1", sfr: Some(0xCE), xdata: Some(0xDFCE), fields: &[
+    T3CCTL1_IM, T3CCTL1_CMP, T3CCTL1_MODE,
+]};
+pub const T3CC1: Register = Register{name: "T3CC1", sfr: Some(0xCF), xdata: Some(0xDFCF), fields: &[]};
+pub const PSW_CY: Field = Field{name: "CY", mask: 0x80, values: &[]};
+pub const PSW_AC: Field = Field{name: "AC", mask: 0x40, values: &[]};
+pub const PSW_F0: Field = Field{name: "F0", mask: 0x20, values: &[]};
+pub const PSW_RS1: Field = Field{name: "RS1", mask: 0x10, values: &[]};
+pub const PSW_RS0: Field = Field{name: "RS0", mask: 0x08, values: &[]};
+pub const PSW_OV: Field = Field{name: "OV", mask: 0x04, values: &[]};
+pub const PSW_F1: Field = Field{name: "F1", mask: 0x02, values: &[]};
+pub const PSW_P: Field = Field{name: "P", mask: 0x01, values: &[]};
+pub const PSW: Register = Register{name: "PSW", sfr: Some(0xD0), xdata: None, fields: &[
+    PSW_CY, PSW_AC, PSW_F0, PSW_RS1, PSW_RS0, PSW_OV, PSW_F1, PSW_P,
+]};
+pub const DMAIRQ_DMAIF4: Field = Field{name: "DMAIF4", mask: 0x10, values: &[]};
+pub const DMAIRQ_DMAIF3: Field = Field{name: "DMAIF3", mask: 0x08, values: &[]};
+pub const DMAIRQ_DMAIF2: Field = Field{name: "DMAIF2", mask: 0x04, values: &[]};
+pub const DMAIRQ_DMAIF1: Field = Field{name: "DMAIF1", mask: 0x02, values: &[]};
+pub const DMAIRQ_DMAIF0: Field = Field{name: "DMAIF0", mask: 0x01, values: &[]};
+pub const DMAIRQ: Register = Register{name: "DMAIRQ", sfr: Some(0xD1), xdata: Some(0xDFD1), fields: &[
+    DMAIRQ_DMAIF4, DMAIRQ_DMAIF3, DMAIRQ_DMAIF2, DMAIRQ_DMAIF1, DMAIRQ_DMAIF0,
+]};
+pub const DMA1CFGL: Register = Register{name: "DMA1CFGL", sfr: Some(0xD2), xdata: Some(0xDFD2), fields: &[]};
+pub const DMA1CFGH: Register = Register{name: "DMA1CFGH", sfr: Some(0xD3), xdata: Some(0xDFD3), fields: &[]};
+pub const DMA0CFGL: Register = Register{name: "DMA0CFGL", sfr: Some(0xD4), xdata: Some(0xDFD4), fields: &[]};
+pub const DMA0CFGH: Register = Register{name: "DMA0CFGH", sfr: Some(0xD5), xdata: Some(0xDFD5), fields: &[]};
+pub const DMAARM_ABORT: Field = Field{name: "ABORT", mask: 0x80, values: &[]};
+pub const DMAARM_DMAARM4: Field = Field{name: "DMAARM4", mask: 0x10, values: &[]};
+pub const DMAARM_DMAARM3: Field = Field{name: "DMAARM3", mask: 0x08, values: &[]};
+pub const DMAARM_DMAARM2: Field = Field{name: "DMAARM2", mask: 0x04, values: &[]};
+pub const DMAARM_DMAARM1: Field = Field{name: "DMAARM1", mask: 0x02, values: &[]};
+pub const DMAARM_DMAARM0: Field = Field{name: "DMAARM0", mask: 0x01, values: &[]};
+pub const DMAARM: Register = Register{name: "DMAARM", sfr: Some(0xD6), xdata: Some(0xDFD6), fields: &[
+    DMAARM_ABORT, DMAARM_DMAARM4, DMAARM_DMAARM3, DMAARM_DMAARM2, DMAARM_DMAARM1, DMAARM_DMAARM0,
+]};
+pub const DMAREQ_DMAREQ4: Field = Field{name: "DMAREQ4", mask: 0x10, values: &[]};
+pub const DMAREQ_DMAREQ3: Field = Field{name: "DMAREQ3", mask: 0x08, values: &[]};
+pub const DMAREQ_DMAREQ2: Field = Field{name: "DMAREQ2", mask: 0x04, values: &[]};
+pub const DMAREQ_DMAREQ1: Field = Field{name: "DMAREQ1", mask: 0x02, values: &[]};
+pub const DMAREQ_DMAREQ0: Field = Field{name: "DMAREQ0", mask: 0x01, values: &[]};
+pub const DMAREQ: Register = Register{name: "DMAREQ", sfr: Some(0xD7), xdata: Some(0xDFD7), fields: &[
+    DMAREQ_DMAREQ4, DMAREQ_DMAREQ3, DMAREQ_DMAREQ2, DMAREQ_DMAREQ1, DMAREQ_DMAREQ0,
+]};
+pub const TIMIF_OVFIM: Field = Field{name: "OVFIM", mask: 0x40, values: &[]};
+pub const TIMIF_T4CH1IF: Field = Field{name: "T4CH1IF", mask: 0x20, values: &[]};
+pub const TIMIF_T4CH0IF: Field = Field{name: "T4CH0IF", mask: 0x10, values: &[]};
+pub const TIMIF_T4OVFIF: Field = Field{name: "T4OVFIF", mask: 0x08, values: &[]};
+pub const TIMIF_T3CH1IF: Field = Field{name: "T3CH1IF", mask: 0x04, values: &[]};
+pub const TIMIF_T3CH0IF: Field = Field{name: "T3CH0IF", mask: 0x02, values: &[]};
+pub const TIMIF_T3OVFIF: Field = Field{name: "T3OVFIF", mask: 0x01, values: &[]};
+pub const TIMIF: Register = Register{name: "TIMIF", sfr: Some(0xD8), xdata: Some(0xDFD8), fields: &[
+    TIMIF_OVFIM, TIMIF_T4CH1IF, TIMIF_T4CH0IF, TIMIF_T4OVFIF, TIMIF_T3CH1IF, TIMIF_T3CH0IF,
+    TIMIF_T3OVFIF,
+]};
+pub const RFD: Register = Register{name: "RFD", sfr: Some(0xD9), xdata: Some(0xDFD9), fields: &[]};
+pub const T1CC0L: Register = Register{name: "T1CC0L", sfr: Some(0xDA), xdata: Some(0xDFDA), fields: &[]};
+pub const T1CC0H: Register = Register{name: "T1CC0H", sfr: Some(0xDB), xdata: Some(0xDFDB), fields: &[]};
+pub const T1CC1L: Register = Register{name: "T1CC1L", sfr: Some(0xDC), xdata: Some(0xDFDC), fields: &[]};
+pub const T1CC1H: Register = Register{name: "T1CC1H", sfr: Some(0xDD), xdata: Some(0xDFDD), fields: &[]};
+pub const T1CC2L: Register = Register{name: "T1CC2L", sfr: Some(0xDE), xdata: Some(0xDFDE), fields: &[]};
+pub const T1CC2H: Register = Register{name: "T1CC2H", sfr: Some(0xDF), xdata: Some(0xDFDF), fields: &[]};
+pub const ACC_7: Field = Field{name: "ACC_7", mask: 0x80, values: &[]};
+pub const ACC_6: Field = Field{name: "ACC_6", mask: 0x40, values: &[]};
+pub const ACC_5: Field = Field{name: "ACC_5", mask: 0x20, values: &[]};
+pub const ACC_4: Field = Field{name: "ACC_4", mask: 0x10, values: &[]};
+pub const ACC_3: Field = Field{name: "ACC_3", mask: 0x08, values: &[]};
+pub const ACC_2: Field = Field{name: "ACC_2", mask: 0x04, values: &[]};
+pub const ACC_1: Field = Field{name: "ACC_1", mask: 0x02, values: &[]};
+pub const ACC_0: Field = Field{name: "ACC_0", mask: 0x01, values: &[]};
+pub const ACC: Register = Register{name: "ACC", sfr: Some(0xE0), xdata: None, fields: &[
+    ACC_7, ACC_6, ACC_5, ACC_4, ACC_3, ACC_2, ACC_1, ACC_0,
+]};
+pub const RFST: Register = Register{name: "RFST", sfr: Some(0xE1), xdata: Some(0xDFE1), fields: &[]};
+pub const T1CNTL: Register = Register{name: "T1CNTL", sfr: Some(0xE2), xdata: Some(0xDFE2), fields: &[]};
+pub const T1CNTH: Register = Register{name: "T1CNTH", sfr: Some(0xE3), xdata: Some(0xDFE3), fields: &[]};
+pub const T1CTL_CH2IF: Field = Field{name: "CH2IF", mask: 0x80, values: &[]};
+pub const T1CTL_CH1IF: Field = Field{name: "CH1IF", mask: 0x40, values: &[]};
+pub const T1CTL_CH0IF: Field = Field{name: "CH0IF", mask: 0x20, values: &[]};
+pub const T1CTL_OVFIF: Field = Field{name: "OVFIF", mask: 0x10, values: &[]};
+pub const T1CTL_DIV: Field = Field{name: "DIV", mask: 0x0C, values: &[
+    FieldValue{name: "1", value: 0x00},
+    FieldValue{name: "8", value: 0x04},
+    FieldValue{name: "32", value: 0x08},
+    FieldValue{name: "128", value: 0x0C},
+]};
+pub const T1CTL_MODE: Field = Field{name: "MODE", mask: 0x03, values: &[
+    FieldValue{name: "SUSPEND", value: 0x00},
+    FieldValue{name: "FREERUN", value: 0x01},
+    FieldValue{name: "MODULO", value: 0x02},
+    FieldValue{name: "UPDOWN", value: 0x03},
+]};
+pub const T1CTL: Register = Register{name: "T1CTL", sfr: Some(0xE4), xdata: Some(0xDFE4), fields: &[
+    T1CTL_CH2IF, T1CTL_CH1IF, T1CTL_CH0IF, T1CTL_OVFIF, T1CTL_DIV, T1CTL_MODE,
+]};
+pub const T1CCTL0_CPSEL: Field = Field{name: "CPSEL", mask: 0x80, values: &[]};
+pub const T1CCTL0_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T1CCTL0_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+]};
+pub const T1CCTL0_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T1CCTL0_CAP: Field = Field{name: "CAP", mask: 0x03, values: &[
+    FieldValue{name: "NO_CAP", value: 0x00},
+    FieldValue{name: "RISE_EDGE", value: 0x01},
+    FieldValue{name: "FALL_EDGE", value: 0x02},
+    FieldValue{name: "BOTH_EDGE", value: 0x03},
+]};
+pub const T1CCTL0: Register = Register{name: "T1CCTL0", sfr: Some(0xE5), xdata: Some(0xDFE5), fields: &[
+    T1CCTL0_CPSEL, T1CCTL0_IM, T1CCTL0_CMP, T1CCTL0_MODE, T1CCTL0_CAP,
+]};
+pub const T1CCTL1_CPSEL: Field = Field{name: "CPSEL", mask: 0x80, values: &[]};
+pub const T1CCTL1_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T1CCTL1_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+    FieldValue{name: "SET_C1_CLR_C0", value: 0x28},
+    FieldValue{name: "CLR_C1_SET_C0", value: 0x30},
+    FieldValue{name: "DSM_MODE", value: 0x38},
+]};
+pub const T1CCTL1_DSM_SPD: Field = Field{name: "DSM_SPD", mask: 0x04, values: &[]};
+pub const T1CCTL1_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T1CCTL1_CAP: Field = Field{name: "CAP", mask: 0x03, values: &[
+    FieldValue{name: "NO_CAP", value: 0x00},
+    FieldValue{name: "RISE_EDGE", value: 0x01},
+    FieldValue{name: "FALL_EDGE", value: 0x02},
+    FieldValue{name: "BOTH_EDGE", value: 0x03},
+]};
+pub const T1CCTL1: Register = Register{name: "T1CCTL1", sfr: Some(0xE6), xdata: Some(0xDFE6), fields: &[
+    T1CCTL1_CPSEL, T1CCTL1_IM, T1CCTL1_CMP, T1CCTL1_DSM_SPD, T1CCTL1_MODE, T1CCTL1_CAP,
+]};
+pub const T1CCTL2_CPSEL: Field = Field{name: "CPSEL", mask: 0x80, values: &[]};
+pub const T1CCTL2_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T1CCTL2_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+    FieldValue{name: "SET_C2_CLR_C0", value: 0x28},
+    FieldValue{name: "CLR_C2_SET_C0", value: 0x30},
+]};
+pub const T1CCTL2_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T1CCTL2_CAP: Field = Field{name: "CAP", mask: 0x03, values: &[
+    FieldValue{name: "NO_CAP", value: 0x00},
+    FieldValue{name: "RISE_EDGE", value: 0x01},
+    FieldValue{name: "FALL_EDGE", value: 0x02},
+    FieldValue{name: "BOTH_EDGE", value: 0x03},
+]};
+pub const T1CCTL2: Register = Register{name: "T1CCTL2", sfr: Some(0xE7), xdata: Some(0xDFE7), fields: &[
+    T1CCTL2_CPSEL, T1CCTL2_IM, T1CCTL2_CMP, T1CCTL2_MODE, T1CCTL2_CAP,
+]};
+pub const IRCON2_WDTIF: Field = Field{name: "WDTIF", mask: 0x10, values: &[]};
+pub const IRCON2_P1IF: Field = Field{name: "P1IF", mask: 0x08, values: &[]};
+pub const IRCON2_UTX1IF: Field = Field{name: "UTX1IF", mask: 0x04, values: &[]};
+pub const IRCON2_UTX0IF: Field = Field{name: "UTX0IF", mask: 0x02, values: &[]};
+pub const IRCON2_P2IF: Field = Field{name: "P2IF", mask: 0x01, values: &[]};
+pub const IRCON2: Register = Register{name: "IRCON2", sfr: Some(0xE8), xdata: None, fields: &[
+    IRCON2_WDTIF, IRCON2_P1IF, IRCON2_UTX1IF, IRCON2_UTX0IF, IRCON2_P2IF,
+]};
+pub const RFIF_IRQ_TXUNF: Field = Field{name: "IRQ_TXUNF", mask: 0x80, values: &[]};
+pub const RFIF_IRQ_RXOVF: Field = Field{name: "IRQ_RXOVF", mask: 0x40, values: &[]};
+pub const RFIF_IRQ_TIMEOUT: Field = Field{name: "IRQ_TIMEOUT", mask: 0x20, values: &[]};
+pub const RFIF_IRQ_DONE: Field = Field{name: "IRQ_DONE", mask: 0x10, values: &[]};
+pub const RFIF_IRQ_CS: Field = Field{name: "IRQ_CS", mask: 0x08, values: &[]};
+pub const RFIF_IRQ_PQT: Field = Field{name: "IRQ_PQT", mask: 0x04, values: &[]};
+pub const RFIF_IRQ_CCA: Field = Field{name: "IRQ_CCA", mask: 0x02, values: &[]};
+pub const RFIF_IRQ_SFD: Field = Field{name: "IRQ_SFD", mask: 0x01, values: &[]};
+pub const RFIF: Register = Register{name: "RFIF", sfr: Some(0xE9), xdata: Some(0xDFE9), fields: &[
+    RFIF_IRQ_TXUNF, RFIF_IRQ_RXOVF, RFIF_IRQ_TIMEOUT, RFIF_IRQ_DONE, RFIF_IRQ_CS, RFIF_IRQ_PQT,
+    RFIF_IRQ_CCA, RFIF_IRQ_SFD,
+]};
+pub const T4CNT: Register = Register{name: "T4CNT", sfr: Some(0xEA), xdata: Some(0xDFEA), fields: &[]};
+pub const T4CTL_DIV: Field = Field{name: "DIV", mask: 0xE0, values: &[
+    FieldValue{name: "1", value: 0x00},
+    FieldValue{name: "2", value: 0x20},
+    FieldValue{name: "4", value: 0x40},
+    FieldValue{name: "8", value: 0x60},
+    FieldValue{name: "16", value: 0x80},
+    FieldValue{name: "32", value: 0xA0},
+    FieldValue{name: "64", value: 0xC0},
+    FieldValue{name: "128", value: 0xE0},
+]};
+pub const T4CTL_START: Field = Field{name: "START", mask: 0x10, values: &[]};
+pub const T4CTL_OVFIM: Field = Field{name: "OVFIM", mask: 0x08, values: &[]};
+pub const T4CTL_CLR: Field = Field{name: "CLR", mask: 0x04, values: &[]};
+pub const T4CTL_MODE: Field = Field{name: "MODE", mask: 0x03, values: &[
+    FieldValue{name: "FREERUN", value: 0x00},
+    FieldValue{name: "DOWN", value: 0x01},
+    FieldValue{name: "MODULO", value: 0x02},
+    FieldValue{name: "UPDOWN", value: 0x03},
+]};
+pub const T4CTL: Register = Register{name: "T4CTL", sfr: Some(0xEB), xdata: Some(0xDFEB), fields: &[
+    T4CTL_DIV, T4CTL_START, T4CTL_OVFIM, T4CTL_CLR, T4CTL_MODE,
+]};
+pub const T4CCTL0_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T4CCTL0_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+    FieldValue{name: "SET_CMP_CLR_255", value: 0x28},
+    FieldValue{name: "CLR_CMP_SET_0", value: 0x30},
+]};
+pub const T4CCTL0_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T4CCTL0: Register = Register{name: "T4CCTL0", sfr: Some(0xEC), xdata: Some(0xDFEC), fields: &[
+    T4CCTL0_IM, T4CCTL0_CMP, T4CCTL0_MODE,
+]};
+pub const T4CC0: Register = Register{name: "T4CC0", sfr: Some(0xED), xdata: Some(0xDFED), fields: &[]};
+pub const T4CCTL1_IM: Field = Field{name: "IM", mask: 0x40, values: &[]};
+pub const T4CCTL1_CMP: Field = Field{name: "CMP", mask: 0x38, values: &[
+    FieldValue{name: "SET_ON_CMP", value: 0x00},
+    FieldValue{name: "CLR_ON_CMP", value: 0x08},
+    FieldValue{name: "TOG_ON_CMP", value: 0x10},
+    FieldValue{name: "SET_CMP_UP_CLR_0", value: 0x18},
+    FieldValue{name: "CLR_CMP_UP_SET_0", value: 0x20},
+    FieldValue{name: "SET_CMP_CLR_C0", value: 0x28},
+    FieldValue{name: "CLR_CMP_SET_C0", value: 0x30},
+]};
+pub const T4CCTL1_MODE: Field = Field{name: "MODE", mask: 0x04, values: &[]};
+pub const T4CCTL1: Register = Register{name: "T4CCTL1", sfr: Some(0xEE), xdata: Some(0xDFEE), fields: &[
+    T4CCTL1_IM, T4CCTL1_CMP, T4CCTL1_MODE,
+]};
+pub const T4CC1: Register = Register{name: "T4CC1", sfr: Some(0xEF), xdata: Some(0xDFEF), fields: &[]};
+pub const B_7: Field = Field{name: "B_7", mask: 0x80, values: &[]};
+pub const B_6: Field = Field{name: "B_6", mask: 0x40, values: &[]};
+pub const B_5: Field = Field{name: "B_5", mask: 0x20, values: &[]};
+pub const B_4: Field = Field{name: "B_4", mask: 0x10, values: &[]};
+pub const B_3: Field = Field{name: "B_3", mask: 0x08, values: &[]};
+pub const B_2: Field = Field{name: "B_2", mask: 0x04, values: &[]};
+pub const B_1: Field = Field{name: "B_1", mask: 0x02, values: &[]};
+pub const B_0: Field = Field{name: "B_0", mask: 0x01, values: &[]};
+pub const B: Register = Register{name: "B", sfr: Some(0xF0), xdata: None, fields: &[
+    B_7, B_6, B_5, B_4, B_3, B_2, B_1, B_0,
+]};
+pub const PERCFG_T1CFG: Field = Field{name: "T1CFG", mask: 0x40, values: &[]};
+pub const PERCFG_T3CFG: Field = Field{name: "T3CFG", mask: 0x20, values: &[]};
+pub const PERCFG_T4CFG: Field = Field{name: "T4CFG", mask: 0x10, values: &[]};
+pub const PERCFG_U1CFG: Field = Field{name: "U1CFG", mask: 0x02, values: &[]};
+pub const PERCFG_U0CFG: Field = Field{name: "U0CFG", mask: 0x01, values: &[]};
+pub const PERCFG: Register = Register{name: "PERCFG", sfr: Some(0xF1), xdata: Some(0xDFF1), fields: &[
+    PERCFG_T1CFG, PERCFG_T3CFG, PERCFG_T4CFG, PERCFG_U1CFG, PERCFG_U0CFG,
+]};
+pub const ADCCFG_7: Field = Field{name: "ADCCFG_7", mask: 0x80, values: &[]};
+pub const ADCCFG_6: Field = Field{name: "ADCCFG_6", mask: 0x40, values: &[]};
+pub const ADCCFG_5: Field = Field{name: "ADCCFG_5", mask: 0x20, values: &[]};
+pub const ADCCFG_4: Field = Field{name: "ADCCFG_4", mask: 0x10, values: &[]};
+pub const ADCCFG_3: Field = Field{name: "ADCCFG_3", mask: 0x08, values: &[]};
+pub const ADCCFG_2: Field = Field{name: "ADCCFG_2", mask: 0x04, values: &[]};
+pub const ADCCFG_1: Field = Field{name: "ADCCFG_1", mask: 0x02, values: &[]};
+pub const ADCCFG_0: Field = Field{name: "ADCCFG_0", mask: 0x01, values: &[]};
+pub const ADCCFG: Register = Register{name: "ADCCFG", sfr: Some(0xF2), xdata: Some(0xDFF2), fields: &[
+    ADCCFG_7, ADCCFG_6, ADCCFG_5, ADCCFG_4, ADCCFG_3, ADCCFG_2, ADCCFG_1, ADCCFG_0,
+]};
+pub const P0SEL: Register = Register{name: "P0SEL", sfr: Some(0xF3), xdata: Some(0xDFF3), fields: &[]};
+pub const P1SEL: Register = Register{name: "P1SEL", sfr: Some(0xF4), xdata: Some(0xDFF4), fields: &[]};
+pub const P2SEL_PRI3P1: Field = Field{name: "PRI3P1", mask: 0x40, values: &[]};
+pub const P2SEL_PRI2P1: Field = Field{name: "PRI2P1", mask: 0x20, values: &[]};
+pub const P2SEL_PRI1P1: Field = Field{name: "PRI1P1", mask: 0x10, values: &[]};
+pub const P2SEL_PRI0P1: Field = Field{name: "PRI0P1", mask: 0x08, values: &[]};
+pub const P2SEL_SELP2_4: Field = Field{name: "SELP2_4", mask: 0x04, values: &[]};
+pub const P2SEL_SELP2_3: Field = Field{name: "SELP2_3", mask: 0x02, values: &[]};
+pub const P2SEL_SELP2_0: Field = Field{name: "SELP2_0", mask: 0x01, values: &[]};
+pub const P2SEL: Register = Register{name: "P2SEL", sfr: Some(0xF5), xdata: Some(0xDFF5), fields: &[
+    P2SEL_PRI3P1, P2SEL_PRI2P1, P2SEL_PRI1P1, P2SEL_PRI0P1, P2SEL_SELP2_4, P2SEL_SELP2_3,
+    P2SEL_SELP2_0,
+]};
+pub const P1INP: Register = Register{name: "P1INP", sfr: Some(0xF6), xdata: Some(0xDFF6), fields: &[]};
+pub const P2INP_PDUP2: Field = Field{name: "PDUP2", mask: 0x80, values: &[]};
+pub const P2INP_PDUP1: Field = Field{name: "PDUP1", mask: 0x40, values: &[]};
+pub const P2INP_PDUP0: Field = Field{name: "PDUP0", mask: 0x20, values: &[]};
+pub const P2INP_MDP2_4: Field = Field{name: "MDP2_4", mask: 0x10, values: &[]};
+pub const P2INP_MDP2_3: Field = Field{name: "MDP2_3", mask: 0x08, values: &[]};
+pub const P2INP_MDP2_2: Field = Field{name: "MDP2_2", mask: 0x04, values: &[]};
+pub const P2INP_MDP2_1: Field = Field{name: "MDP2_1", mask: 0x02, values: &[]};
+pub const P2INP_MDP2_0: Field = Field{name: "MDP2_0", mask: 0x01, values: &[]};
+pub const P2INP: Register = Register{name: "P2INP", sfr: Some(0xF7), xdata: Some(0xDFF7), fields: &[
+    P2INP_PDUP2, P2INP_PDUP1, P2INP_PDUP0, P2INP_MDP2_4, P2INP_MDP2_3, P2INP_MDP2_2, P2INP_MDP2_1,
+    P2INP_MDP2_0,
+]};
+pub const U1CSR_MODE: Field = Field{name: "MODE", mask: 0x80, values: &[]};
+pub const U1CSR_RE: Field = Field{name: "RE", mask: 0x40, values: &[]};
+pub const U1CSR_SLAVE: Field = Field{name: "SLAVE", mask: 0x20, values: &[]};
+pub const U1CSR_FE: Field = Field{name: "FE", mask: 0x10, values: &[]};
+pub const U1CSR_ERR: Field = Field{name: "ERR", mask: 0x08, values: &[]};
+pub const U1CSR_RX_BYTE: Field = Field{name: "RX_BYTE", mask: 0x04, values: &[]};
+pub const U1CSR_TX_BYTE: Field = Field{name: "TX_BYTE", mask: 0x02, values: &[]};
+pub const U1CSR_ACTIVE: Field = Field{name: "ACTIVE", mask: 0x01, values: &[]};
+pub const U1CSR: Register = Register{name: "U1CSR", sfr: Some(0xF8), xdata: Some(0xDFF8), fields: &[
+    U1CSR_MODE, U1CSR_RE, U1CSR_SLAVE, U1CSR_FE, U1CSR_ERR, U1CSR_RX_BYTE, U1CSR_TX_BYTE,
+    U1CSR_ACTIVE,
+]};
+pub const U1DBUF: Register = Register{name: "U1DBUF", sfr: Some(0xF9), xdata: Some(0xDFF9), fields: &[]};
+pub const U1BAUD: Register = Register{name: "U1BAUD", sfr: Some(0xFA), xdata: Some(0xDFFA), fields: &[]};
+pub const U1UCR_FLUSH: Field = Field{name: "FLUSH", mask: 0x80, values: &[]};
+pub const U1UCR_FLOW: Field = Field{name: "FLOW", mask: 0x40, values: &[]};
+pub const U1UCR_D9: Field = Field{name: "D9", mask: 0x20, values: &[]};
+pub const U1UCR_BIT9: Field = Field{name: "BIT9", mask: 0x10, values: &[]};
+pub const U1UCR_PARITY: Field = Field{name: "PARITY", mask: 0x08, values: &[]};
+pub const U1UCR_SPB: Field = Field{name: "SPB", mask: 0x04, values: &[]};
+pub const U1UCR_STOP: Field = Field{name: "STOP", mask: 0x02, values: &[]};
+pub const U1UCR_START: Field = Field{name: "START", mask: 0x01, values: &[]};
+pub const U1UCR: Register = Register{name: "U1UCR", sfr: Some(0xFB), xdata: Some(0xDFFB), fields: &[
+    U1UCR_FLUSH, U1UCR_FLOW, U1UCR_D9, U1UCR_BIT9, U1UCR_PARITY, U1UCR_SPB, U1UCR_STOP, U1UCR_START,
+]};
+pub const U1GCR_CPOL: Field = Field{name: "CPOL", mask: 0x80, values: &[]};
+pub const U1GCR_CPHA: Field = Field{name: "CPHA", mask: 0x40, values: &[]};
+pub const U1GCR_ORDER: Field = Field{name: "ORDER", mask: 0x20, values: &[]};
+pub const U1GCR_BAUD_E: Field = Field{name: "BAUD_E", mask: 0x1F, values: &[]};
+pub const U1GCR: Register = Register{name: "U1GCR", sfr: Some(0xFC), xdata: Some(0xDFFC), fields: &[
+    U1GCR_CPOL, U1GCR_CPHA, U1GCR_ORDER, U1GCR_BAUD_E,
+]};
+pub const P0DIR: Register = Register{name: "P0DIR", sfr: Some(0xFD), xdata: Some(0xDFFD), fields: &[]};
+pub const P1DIR: Register = Register{name: "P1DIR", sfr: Some(0xFE), xdata: Some(0xDFFE), fields: &[]};
+pub const P2DIR_PRIP0: Field = Field{name: "PRIP0", mask: 0xC0, values: &[
+    FieldValue{name: "0", value: 0x00},
+    FieldValue{name: "1", value: 0x40},
+    FieldValue{name: "2", value: 0x80},
+    FieldValue{name: "3", value: 0xC0},
+]};
+pub const P2DIR_DIRP2_4: Field = Field{name: "DIRP2_4", mask: 0x10, values: &[]};
+pub const P2DIR_DIRP2_3: Field = Field{name: "DIRP2_3", mask: 0x08, values: &[]};
+pub const P2DIR_DIRP2_2: Field = Field{name: "DIRP2_2", mask: 0x04, values: &[]};
+pub const P2DIR_DIRP2_1: Field = Field{name: "DIRP2_1", mask: 0x02, values: &[]};
+pub const P2DIR_DIRP2_0: Field = Field{name: "DIRP2_0", mask: 0x01, values: &[]};
+pub const P2DIR: Register = Register{name: "P2DIR", sfr: Some(0xFF), xdata: Some(0xDFFF), fields: &[
+    P2DIR_PRIP0, P2DIR_DIRP2_4, P2DIR_DIRP2_3, P2DIR_DIRP2_2, P2DIR_DIRP2_1, P2DIR_DIRP2_0,
+]};
+
+// the firmware's AES mode byte (set_aes_mode / get_aes_mode): no address, but laid out like a register
+pub const AES_CRYPTO_MODE: Field = Field{name: "MODE", mask: 0xF0, values: &[
+    FieldValue{name: "CBC", value: 0x00},
+    FieldValue{name: "CFB", value: 0x10},
+    FieldValue{name: "OFB", value: 0x20},
+    FieldValue{name: "CTR", value: 0x30},
+    FieldValue{name: "ECB", value: 0x40},
+    FieldValue{name: "CBCMAC", value: 0x50},
+]};
+pub const AES_CRYPTO_OUT_ENABLE: Field = Field{name: "OUT_ENABLE", mask: 0x08, values: &[
+    FieldValue{name: "OFF", value: 0x00},
+    FieldValue{name: "ON", value: 0x08},
+]};
+pub const AES_CRYPTO_OUT_TYPE: Field = Field{name: "OUT_TYPE", mask: 0x04, values: &[
+    FieldValue{name: "DECRYPT", value: 0x00},
+    FieldValue{name: "ENCRYPT", value: 0x04},
+]};
+pub const AES_CRYPTO_IN_ENABLE: Field = Field{name: "IN_ENABLE", mask: 0x02, values: &[
+    FieldValue{name: "OFF", value: 0x00},
+    FieldValue{name: "ON", value: 0x02},
+]};
+pub const AES_CRYPTO_IN_TYPE: Field = Field{name: "IN_TYPE", mask: 0x01, values: &[
+    FieldValue{name: "DECRYPT", value: 0x00},
+    FieldValue{name: "ENCRYPT", value: 0x01},
+]};
+pub const AES_CRYPTO: Register = Register{name: "AES_CRYPTO", sfr: None, xdata: None, fields: &[
+    AES_CRYPTO_MODE, AES_CRYPTO_OUT_ENABLE, AES_CRYPTO_OUT_TYPE, AES_CRYPTO_IN_ENABLE, AES_CRYPTO_IN_TYPE,
+]};
+// CBC, encrypting outbound and decrypting inbound
+pub const AES_CRYPTO_DEFAULT: u8 = 0x0E;
+
+/* every register above with an address, in address order (XDATA first) */
+pub const REGISTERS: &[Register] = &[
+    USBADDR, USBPOW, USBIIF, USBOIF, USBCIF, USBIIE, USBOIE, USBCIE, USBFRML, USBFRMH, USBINDEX,
+    USBMAXI, USBCS0, USBCSIL, USBCSIH, USBMAXO, USBCSOL, USBCSOH, USBCNT0, USBCNTL, USBCNTH, USBF0,
+    USBF1, USBF2, USBF3, USBF4, USBF5, SYNC1, SYNC0, PKTLEN, PKTCTRL1, PKTCTRL0, ADDR, CHANNR,
+    FSCTRL1, FSCTRL0, FREQ2, FREQ1, FREQ0, MDMCFG4, MDMCFG3, MDMCFG2, MDMCFG1, MDMCFG0, DEVIATN,
+    MCSM2, MCSM1, MCSM0, FOCCFG, BSCFG, AGCCTRL2, AGCCTRL1, AGCCTRL0, FREND1, FREND0, FSCAL3,
+    FSCAL2, FSCAL1, FSCAL0, TEST2, TEST1, TEST0, PA_TABLE7, PA_TABLE6, PA_TABLE5, PA_TABLE4,
+    PA_TABLE3, PA_TABLE2, PA_TABLE1, PA_TABLE0, IOCFG2, IOCFG1, IOCFG0, PARTNUM, VERSION, FREQEST,
+    LQI, RSSI, MARCSTATE, PKTSTATUS, VCO_VC_DAC, I2SCFG0, I2SCFG1, I2SDATL, I2SDATH, I2SWCNT,
+    I2SSTAT, I2SCLKF0, I2SCLKF1, I2SCLKF2, P0, SP, DPL0, DPH0, DPL1, DPH1, U0CSR, PCON, TCON, P0IFG,
+    P1IFG, P2IFG, PICTL, P1IEN, P0INP, P1, RFIM, DPS, MPAGE, S0CON, IEN2, S1CON, T2CT, T2PR, T2CTL,
+    P2, WORIRQ, WORCTRL, WOREVT0, WOREVT1, WORTIME0, WORTIME1, IEN0, IP0, FWT, FADDRL, FADDRH, FCTL,
+    FWDATA, ENCDI, ENCDO, ENCCS, ADCCON1, ADCCON2, ADCCON3, IEN1, IP1, ADCL, ADCH, RNDL, RNDH,
+    SLEEP, IRCON, U0DBUF, U0BAUD, U0UCR, U0GCR, CLKCON, MEMCTR, WDCTL, T3CNT, T3CTL, T3CCTL0, T3CC0,
+    T3CCTL1, T3CC1, PSW, DMAIRQ, DMA1CFGL, DMA1CFGH, DMA0CFGL, DMA0CFGH, DMAARM, DMAREQ, TIMIF, RFD,
+    T1CC0L, T1CC0H, T1CC1L, T1CC1H, T1CC2L, T1CC2H, ACC, RFST, T1CNTL, T1CNTH, T1CTL, T1CCTL0,
+    T1CCTL1, T1CCTL2, IRCON2, RFIF, T4CNT, T4CTL, T4CCTL0, T4CC0, T4CCTL1, T4CC1, B, PERCFG, ADCCFG,
+    P0SEL, P1SEL, P2SEL, P1INP, P2INP, U1CSR, U1DBUF, U1BAUD, U1UCR, U1GCR, P0DIR, P1DIR, P2DIR,
+];
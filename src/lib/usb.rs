@@ -0,0 +1,161 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::transport::{Transport, TransportIdentity};
+
+// one libusb context for the whole process, never torn down
+static SHARED_CONTEXT: Mutex<Option<&'static libusb::Context>> = Mutex::new(None);
+
+/*
+ * the process-wide libusb context.  devices opened through it are
+ * RFCatDevice<'static>: no borrow to keep alive, free to move between threads.
+ */
+pub fn shared_context() -> Result<&'static libusb::Context, Error> {
+    let mut shared = match SHARED_CONTEXT.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(context) = *shared {
+        return Ok(context);
+    }
+    let context: &'static libusb::Context = Box::leak(Box::new(libusb::Context::new()?));
+    *shared = Some(context);
+    Ok(context)
+}
+
+/* UART-over-USB: RfCat frames ride the CC's bulk endpoints */
+pub struct UsbTransport<'a> {
+    bus_number: u8,
+    address: u8,
+    vendor_id: u16,
+    product_id: u16,
+    handle: libusb::DeviceHandle<'a>,
+    descriptor: libusb::DeviceDescriptor,
+    language: Option<libusb::Language>,
+    timeout: Duration,
+    max_input_size: u16,
+    in_endpoint_address: u8,
+    out_endpoint_address: u8,
+}
+
+impl<'a> UsbTransport<'a> {
+
+    pub fn open(
+        device: libusb::Device<'a>,
+        device_desc: libusb::DeviceDescriptor,
+    ) -> Result<UsbTransport<'a>, Error> {
+        let mut handle = device.open()?;
+        let bus_number = device.bus_number();
+        let address = device.address();
+        let vendor_id = device_desc.vendor_id();
+        let product_id = device_desc.product_id();
+        let timeout = Duration::from_secs(1);
+        let langs = handle.read_languages(timeout)?;
+        let language: Option<libusb::Language>;
+        if langs.len() > 0 {
+            language = Some(langs[0]);
+        } else {
+            language = None;
+        }
+        let mut in_max_size: u16 = 64;
+        let mut in_ep_addr: u8 = 0;
+        let mut out_ep_addr: u8 = 0;
+        handle.reset()?;
+        for n in 0..device_desc.num_configurations() {
+            let config_desc = device.config_descriptor(n)?;
+            for interface in config_desc.interfaces() {
+                for interface_desc in interface.descriptors() {
+                    for endpoint_desc in interface_desc.endpoint_descriptors() {
+                        if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
+                                endpoint_desc.direction() == libusb::Direction::In {
+                            in_ep_addr = endpoint_desc.address();
+                            in_max_size = endpoint_desc.max_packet_size();
+                        }
+                        if endpoint_desc.transfer_type() == libusb::TransferType::Bulk &&
+                                endpoint_desc.direction() == libusb::Direction::Out {
+                            out_ep_addr = endpoint_desc.address();
+                        }
+
+                    }
+                }
+            }
+        }
+        handle.set_active_configuration(1)?;
+        handle.claim_interface(0)?;
+        handle.set_alternate_setting(0, 0)?;
+        Ok(UsbTransport{
+            bus_number: bus_number,
+            address: address,
+            vendor_id: vendor_id,
+            product_id: product_id,
+            handle: handle,
+            descriptor: device_desc,
+            language: language,
+            timeout: timeout,
+            max_input_size: in_max_size,
+            in_endpoint_address: in_ep_addr,
+            out_endpoint_address: out_ep_addr,
+        })
+    }
+}
+
+impl<'a> Transport for UsbTransport<'a> {
+
+    fn send_frame(&self, frame: &[u8]) -> Result<usize, Error> {
+        Ok(self.handle.write_bulk(self.out_endpoint_address, frame, self.timeout)?)
+    }
+
+    fn recv_frame(&self) -> Result<Vec<u8>, Error> {
+        self.recv_frame_timeout(self.timeout)
+    }
+
+    fn recv_frame_timeout(&self, timeout: Duration) -> Result<Vec<u8>, Error> {
+        let mut in_vec = vec![0u8; self.max_input_size as usize];
+        match self.handle.read_bulk(self.in_endpoint_address, &mut in_vec[..], timeout) {
+            Ok(rlen) => {
+                in_vec.truncate(rlen);
+                Ok(in_vec)
+            },
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    fn identity(&self) -> TransportIdentity {
+        TransportIdentity{
+            bus_number: self.bus_number,
+            address: self.address,
+            vendor_id: self.vendor_id,
+            product_id: self.product_id,
+        }
+    }
+
+    fn manufacturer(&self) -> Result<String, Error> {
+        match self.language {
+            Some(language) => Ok(self.handle.read_manufacturer_string(language, &self.descriptor, self.timeout)?),
+            None => Err(Error::Unsupported("descriptor strings")),
+        }
+    }
+
+    fn product(&self) -> Result<String, Error> {
+        match self.language {
+            Some(language) => Ok(self.handle.read_product_string(language, &self.descriptor, self.timeout)?),
+            None => Err(Error::Unsupported("descriptor strings")),
+        }
+    }
+
+    fn serial_number(&self) -> Result<String, Error> {
+        match self.language {
+            Some(language) => Ok(self.handle.read_serial_number_string(language, &self.descriptor, self.timeout)?),
+            None => Err(Error::Unsupported("descriptor strings")),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::time::{Duration, Instant};
+
+use crate::error::Error;
+use crate::ihex::{FirmwareImage, HexRecord, RecordType};
+use crate::RFCatBLDevice;
+
+// CC1111F32 flash, as CC-Bootloader lays it out
+pub const FLASH_SIZE: usize = 0x8000;
+pub const FLASH_PAGE_SIZE: usize = 0x400;
+// everything below here is the bootloader itself: hands off
+pub const USER_CODE_BASE: u16 = 0x1400;
+
+// data bytes per write record (what the usual .hex files carry, too)
+const WRITE_RECORD_LEN: usize = 16;
+// erasing or dumping a page keeps the CC busy for a while
+const PAGE_TIMEOUT: Duration = Duration::from_secs(2);
+// and erasing all of them, longer
+const ERASE_ALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+// what CC-Bootloader answers a record with
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum BootloaderStatus {
+    Ok = 0x00,
+    HexInvalid = 0x01,
+    BadChecksum = 0x02,
+    BadAddress = 0x03,
+    BadRecordType = 0x04,
+    RecordTooLong = 0x05,
+    UNKNOWN = 0xff,
+}
+
+impl From<u8> for BootloaderStatus {
+    fn from(orig: u8) -> Self {
+        match orig {
+            0x00 => BootloaderStatus::Ok,
+            0x01 => BootloaderStatus::HexInvalid,
+            0x02 => BootloaderStatus::BadChecksum,
+            0x03 => BootloaderStatus::BadAddress,
+            0x04 => BootloaderStatus::BadRecordType,
+            0x05 => BootloaderStatus::RecordTooLong,
+            _ => BootloaderStatus::UNKNOWN,
+        }
+    }
+}
+
+/* where a flash is at, handed to the progress callback after every page */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FlashProgress {
+    pub page: u16,
+    pub done: usize,
+    pub total: usize,
+}
+
+fn check_user_page(page: u16) -> Result<(), Error> {
+    if (page as usize) < USER_CODE_BASE as usize / FLASH_PAGE_SIZE || page as usize >= FLASH_SIZE / FLASH_PAGE_SIZE {
+        return Err(Error::InvalidArgument(format!("page {} is outside user flash", page)));
+    }
+    Ok(())
+}
+
+fn check_user_range(addr: u16, len: usize) -> Result<(), Error> {
+    if addr < USER_CODE_BASE || addr as usize + len > FLASH_SIZE {
+        return Err(Error::InvalidArgument(format!("0x{:04x}+{} is outside user flash", addr, len)));
+    }
+    Ok(())
+}
+
+/*
+ * the CC-Bootloader protocol: Intel HEX records down the CDC data endpoint,
+ * a status byte (or, for reads, more HEX records) back
+ */
+impl<'a> RFCatBLDevice<'a> {
+
+    /* take the CDC data interface away from the kernel's tty driver */
+    fn claim(&mut self) -> Result<(), Error> {
+        if self.claimed {
+            return Ok(());
+        }
+        if let Ok(true) = self.handle.kernel_driver_active(self.data_interface) {
+            self.handle.detach_kernel_driver(self.data_interface)?;
+        }
+        self.handle.claim_interface(self.data_interface)?;
+        self.claimed = true;
+        Ok(())
+    }
+
+    fn send_record(&mut self, record: &HexRecord) -> Result<(), Error> {
+        self.claim()?;
+        let line = format!("{}\n", record.to_line());
+        self.handle.write_bulk(self.out_endpoint_address, line.as_bytes(), self.timeout)?;
+        Ok(())
+    }
+
+    /* one more bulk read's worth into rx */
+    fn fill(&mut self, deadline: Instant) -> Result<(), Error> {
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(Error::Timeout);
+        }
+        let mut in_vec = vec![0u8; self.max_input_size as usize];
+        let rlen = self.handle.read_bulk(self.in_endpoint_address, &mut in_vec[..], deadline - now)?;
+        self.rx.extend(in_vec[..rlen].iter());
+        Ok(())
+    }
+
+    fn read_status(&mut self, timeout: Duration) -> Result<(), Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(status) = self.rx.pop_front() {
+                return match BootloaderStatus::from(status) {
+                    BootloaderStatus::Ok => Ok(()),
+                    status => Err(Error::Bootloader(status)),
+                };
+            }
+            self.fill(deadline)?;
+        }
+    }
+
+    fn read_line(&mut self, deadline: Instant) -> Result<String, Error> {
+        loop {
+            if let Some(end) = self.rx.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = self.rx.drain(..=end).collect();
+                return Ok(String::from_utf8(line)?.trim().to_string());
+            }
+            self.fill(deadline)?;
+        }
+    }
+
+    fn command(&mut self, record: &HexRecord, timeout: Duration) -> Result<(), Error> {
+        self.send_record(record)?;
+        self.read_status(timeout)
+    }
+
+    /*
+     * leave the bootloader for the user code (an EOF record).  the stick
+     * re-enumerates as an RFCat, so there's no answer to wait for.
+     */
+    pub fn run(&mut self) -> Result<(), Error> {
+        self.send_record(&HexRecord::new(RecordType::EndOfFile, 0, vec![]))
+    }
+
+    /* start the bootloader over (it re-enumerates, so no answer) */
+    pub fn reset(&mut self) -> Result<(), Error> {
+        self.send_record(&HexRecord::new(RecordType::ResetBootloader, 0, vec![]))
+    }
+
+    /* have the bootloader check the CRC of the user code; true if it's intact */
+    pub fn check_crc(&mut self) -> Result<bool, Error> {
+        let timeout = self.timeout;
+        match self.command(&HexRecord::new(RecordType::CrcCheck, 0, vec![]), timeout) {
+            Ok(()) => Ok(true),
+            Err(Error::Bootloader(_)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /* erase all of user flash (the bootloader stays put) */
+    pub fn erase_all(&mut self) -> Result<(), Error> {
+        self.command(&HexRecord::new(RecordType::EraseAll, 0, vec![]), ERASE_ALL_TIMEOUT)
+    }
+
+    /* erase one 1K page of user flash */
+    pub fn erase_page(&mut self, page: u16) -> Result<(), Error> {
+        check_user_page(page)?;
+        self.command(&HexRecord::new(RecordType::ErasePage, 0, vec![page as u8]), PAGE_TIMEOUT)
+    }
+
+    /* write data into (already erased) user flash at addr */
+    pub fn write(&mut self, addr: u16, data: &[u8]) -> Result<(), Error> {
+        check_user_range(addr, data.len())?;
+        let timeout = self.timeout;
+        for (i, chunk) in data.chunks(WRITE_RECORD_LEN).enumerate() {
+            let record = HexRecord::new(RecordType::Data, addr + (i * WRITE_RECORD_LEN) as u16, chunk.to_vec());
+            self.command(&record, timeout)?;
+        }
+        Ok(())
+    }
+
+    /* read one 1K page back; the bootloader sends it as data records and an EOF */
+    pub fn read_page(&mut self, page: u16) -> Result<Vec<u8>, Error> {
+        if page as usize >= FLASH_SIZE / FLASH_PAGE_SIZE {
+            return Err(Error::InvalidArgument(format!("page {} is past the end of flash", page)));
+        }
+        self.send_record(&HexRecord::new(RecordType::ReadPage, 0, vec![page as u8]))?;
+        let deadline = Instant::now() + PAGE_TIMEOUT;
+        let base = page as usize * FLASH_PAGE_SIZE;
+        let mut data = vec![0xffu8; FLASH_PAGE_SIZE];
+        loop {
+            let line = self.read_line(deadline)?;
+            if line.is_empty() {
+                continue
+            }
+            let record = match HexRecord::parse(&line) {
+                Ok(record) => record,
+                Err(_) => {
+                    return Err(Error::BadBootloaderReply(line));
+                },
+            };
+            match record.record_type {
+                RecordType::Data => {
+                    match (record.address as usize).checked_sub(base) {
+                        Some(offset) if offset + record.data.len() <= FLASH_PAGE_SIZE => {
+                            data[offset..offset + record.data.len()].copy_from_slice(&record.data[..]);
+                        },
+                        _ => {
+                            return Err(Error::BadBootloaderReply(line));
+                        },
+                    }
+                },
+                RecordType::EndOfFile => return Ok(data),
+                _ => {
+                    return Err(Error::BadBootloaderReply(line));
+                },
+            }
+        }
+    }
+
+    /* read all of user flash back out, page by page */
+    pub fn dump<F>(&mut self, mut progress: F) -> Result<FirmwareImage, Error>
+        where F: FnMut(FlashProgress) {
+        let first = USER_CODE_BASE as usize / FLASH_PAGE_SIZE;
+        let last = FLASH_SIZE / FLASH_PAGE_SIZE;
+        let mut image = FirmwareImage::new();
+        for (done, page) in (first..last).enumerate() {
+            let data = self.read_page(page as u16)?;
+            image.set_page(page as u16, &data[..])?;
+            progress(FlashProgress{page: page as u16, done: done + 1, total: last - first});
+        }
+        Ok(image)
+    }
+
+    /* compare the pages image touches against the device; returns the pages that differ */
+    pub fn verify<F>(&mut self, image: &FirmwareImage, mut progress: F) -> Result<Vec<u16>, Error>
+        where F: FnMut(FlashProgress) {
+        let total = image.page_count();
+        let mut mismatched = Vec::<u16>::new();
+        for (done, (page, data)) in image.pages().enumerate() {
+            let readback = self.read_page(page)?;
+            if readback[..] != *data {
+                mismatched.push(page);
+            }
+            progress(FlashProgress{page: page, done: done + 1, total: total});
+        }
+        Ok(mismatched)
+    }
+
+    /*
+     * put image into user flash: erase, write and read back each page it
+     * touches, checking what came back against what went in byte for byte.
+     * images reaching into the bootloader are refused before anything is erased.
+     */
+    pub fn flash<F>(&mut self, image: &FirmwareImage, mut progress: F) -> Result<(), Error>
+        where F: FnMut(FlashProgress) {
+        if image.is_empty() {
+            return Err(Error::InvalidArgument("empty firmware image".to_string()));
+        }
+        for (page, _) in image.pages() {
+            check_user_page(page)?;
+        }
+        let total = image.page_count();
+        for (done, (page, data)) in image.pages().enumerate() {
+            let base = page * FLASH_PAGE_SIZE as u16;
+            self.erase_page(page)?;
+            for (i, chunk) in data.chunks(WRITE_RECORD_LEN).enumerate() {
+                // erased flash is already 0xFF
+                if chunk.iter().all(|b| *b == 0xff) {
+                    continue
+                }
+                self.write(base + (i * WRITE_RECORD_LEN) as u16, chunk)?;
+            }
+            let readback = self.read_page(page)?;
+            if readback[..] != *data {
+                return Err(Error::VerifyFailed{addr: base});
+            }
+            progress(FlashProgress{page: page, done: done + 1, total: total});
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_flash_bounds() {
+        assert!(check_user_page(4).is_err());
+        assert!(check_user_page(5).is_ok());
+        assert!(check_user_page(31).is_ok());
+        assert!(check_user_page(32).is_err());
+        assert!(check_user_range(0x13ff, 1).is_err());
+        assert!(check_user_range(0x1400, 0x6c00).is_ok());
+        assert!(check_user_range(0x7ff0, 0x11).is_err());
+    }
+}